@@ -0,0 +1,106 @@
+use std::{fmt::Write, time::Duration};
+
+use clap::ValueEnum;
+use common::runner::{Stats, Timings};
+use serde::Serialize;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for reading in a terminal
+    Text,
+    /// One object per day, times in nanoseconds
+    Json,
+    /// A table to paste into a PR or diff across commits
+    Markdown,
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    day: u8,
+    iterations: usize,
+    parse: Phase,
+    part1: Phase,
+    part2: Phase,
+}
+
+#[derive(Serialize)]
+struct Phase {
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+}
+
+impl Report {
+    pub fn new(day: u8, iterations: usize, timings: Timings) -> Self {
+        Report {
+            day,
+            iterations: iterations.max(1),
+            parse: timings.parse.into(),
+            part1: timings.part1.into(),
+            part2: timings.part2.into(),
+        }
+    }
+
+    fn phases(&self) -> [(&'static str, &Phase); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+impl From<Stats> for Phase {
+    fn from(stats: Stats) -> Self {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        Phase {
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            max_ns: nanos(stats.max),
+        }
+    }
+}
+
+impl Phase {
+    fn columns(&self) -> [String; 3] {
+        [self.min_ns, self.median_ns, self.max_ns]
+            .map(|ns| format!("{:.2?}", Duration::from_nanos(ns)))
+    }
+}
+
+pub fn render(reports: &[Report], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Text => {
+            for report in reports {
+                for (name, phase) in report.phases() {
+                    let [min, median, max] = phase.columns();
+                    let _ = writeln!(
+                        out,
+                        "Day {:>2} {:<5}  min {:>10}  median {:>10}  max {:>10}",
+                        report.day, name, min, median, max
+                    );
+                }
+            }
+        }
+        Format::Json => {
+            out = serde_json::to_string_pretty(reports).expect("reports serialize to JSON");
+            out.push('\n');
+        }
+        Format::Markdown => {
+            out.push_str("| Day | Phase | Min | Median | Max |\n");
+            out.push_str("|---:|---|---:|---:|---:|\n");
+            for report in reports {
+                for (name, phase) in report.phases() {
+                    let [min, median, max] = phase.columns();
+                    let _ = writeln!(
+                        out,
+                        "| {} | {} | {} | {} | {} |",
+                        report.day, name, min, median, max
+                    );
+                }
+            }
+        }
+    }
+    out
+}
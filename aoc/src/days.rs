@@ -0,0 +1,27 @@
+use common::{
+    differential::{check, Check},
+    runner::{day, Day},
+};
+
+pub const DAYS: &[Day] = &[
+    day::<day1::Day1>(),
+    day::<day2::Day2>(),
+    day::<day4::Day4>(),
+    day::<day6::Day6>(),
+    day::<day9::Day9>(),
+    day::<day10::Day10>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day16::Day16>(),
+    day::<day17::Day17>(),
+    day::<day18::Day18>(),
+    day::<day19::Day19>(),
+    day::<day20::Day20>(),
+];
+
+/// Pairs of implementations that should agree, for `aoc diff`.
+pub const DIFFERENTIALS: &[Check] = &[check::<day6::GuardWalk>(), check::<day17::Interpreters>()];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
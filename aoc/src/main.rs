@@ -0,0 +1,742 @@
+use std::fs;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{builder::PossibleValuesParser, Parser, Subcommand, ValueEnum};
+use common::{
+    animate::Recorder,
+    answers::{self, Answers},
+    differential::Check,
+    examples,
+    input::InputProvider,
+    load_input, parallel,
+    random::Rng,
+    runner::{Day, PartAnswer},
+    submit::{Outcome, Submitter},
+    Solution,
+};
+
+mod bench;
+mod days;
+mod record;
+mod render;
+mod scaffold;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show debug output on stderr; twice for step-by-step detail
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Threads to run days, and the work inside them, on [default: one per CPU]
+    #[arg(
+        short,
+        long,
+        global = true,
+        default_value_t = 0,
+        hide_default_value = true
+    )]
+    jobs: usize,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one, several or (with no days given) all days
+    Run {
+        /// Days to run, e.g. `aoc run 1 16 20`
+        days: Vec<u8>,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, defaults to `day<N>/input.txt`, fetched if missing (only valid for a single day)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Also draw each day that can be drawn
+        #[arg(short, long, value_enum)]
+        render: Option<render::Format>,
+        /// Also replay each day that simulates something, frame by frame
+        #[arg(short, long, value_enum)]
+        animate: Option<render::Animation>,
+        /// Keep only every Nth frame of an animation
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t = record::Output::Text)]
+        output: record::Output,
+    },
+    /// Time parsing and both parts of one, several or all days
+    Bench {
+        /// Days to benchmark, e.g. `aoc bench 17 20`
+        days: Vec<u8>,
+        /// How many times to run each phase
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Input file, defaults to `day<N>/input.txt`, fetched if missing (only valid for a single day)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How to print the results
+        #[arg(short, long, value_enum, default_value_t = bench::Format::Text)]
+        format: bench::Format,
+    },
+    /// Check answers against the ones recorded in the answers file
+    Verify {
+        /// Days to check, e.g. `aoc verify 16`
+        days: Vec<u8>,
+        /// Expected answers per day, input file and part
+        #[arg(short, long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// List the example inputs in a day's `instructions.txt`, or save some
+    /// of them to the day's `examples/` directory
+    Examples {
+        day: u8,
+        /// Blocks to save, numbered as listed
+        #[arg(short, long)]
+        save: Vec<usize>,
+    },
+    /// Post an answer to the site, computing it first if none is given
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// The answer to send instead of running the day
+        answer: Option<String>,
+        /// Input file, defaults to `day<N>/input.txt`, fetched if missing
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Every attempt so far, used to avoid resubmitting wrong answers
+        #[arg(long, default_value = ".aoc-history.toml")]
+        history: PathBuf,
+    },
+    /// Compare each day's reference and fast implementations on generated
+    /// inputs, shrinking any disagreement to the smallest input showing it
+    Diff {
+        /// Days to check, e.g. `aoc diff 17`
+        days: Vec<u8>,
+        /// Inputs to try per pair
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        cases: usize,
+        /// Seed of the first input; the rest follow on from it
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+        /// Largest input to generate, instead of each pair's own limit
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Generate a random input for a day, to stress or time it at scale
+    Gen {
+        day: u8,
+        /// The same seed and size always give the same input
+        #[arg(short, long, default_value_t = 2024)]
+        seed: u64,
+        /// How big to make it; what this counts depends on the day, and by
+        /// default it matches the real input
+        #[arg(short = 'n', long)]
+        size: Option<usize>,
+        /// Write here instead of to stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Compare two columns of a list file laid out like day 1's input, such
+    /// as the same IDs from several sources
+    Lists {
+        /// Defaults to `day1/input.txt`
+        input: Option<PathBuf>,
+        /// The two columns to compare, counting from 1
+        #[arg(short, long, value_delimiter = ',', default_value = "1,2")]
+        columns: Vec<usize>,
+        /// How to score them; give several to see each
+        #[arg(
+            short,
+            long,
+            value_delimiter = ',',
+            default_value = "distance",
+            value_parser = PossibleValuesParser::new(day1::compare::METRICS.iter().map(|m| m.name())),
+        )]
+        metric: Vec<String>,
+        /// Also list every pair that differs, pairing the smallest of each
+        /// column, then the next smallest, and so on
+        #[arg(long)]
+        report: bool,
+    },
+    /// Explain line by line why each report in a file laid out like day 2's
+    /// input is safe or not, under the puzzle's rules or looser ones
+    Reports {
+        /// Defaults to `day2/input.txt`
+        input: Option<PathBuf>,
+        /// Levels the dampener may drop from each report
+        #[arg(short, long, default_value_t = 1)]
+        dampener: usize,
+        /// Smallest step allowed between adjacent levels that differ
+        #[arg(long, default_value_t = 1)]
+        min_step: i32,
+        /// Largest step allowed between adjacent levels
+        #[arg(long, default_value_t = 3)]
+        max_step: i32,
+        /// Allow adjacent levels to be equal, which makes `--monotonic`
+        /// default to `non-strict`
+        #[arg(long)]
+        plateaus: bool,
+        /// Whether the levels must keep going one way [default: strict]
+        #[arg(long, value_enum)]
+        monotonic: Option<Monotonic>,
+        /// Leave out reports that are safe as they are
+        #[arg(long)]
+        unsafe_only: bool,
+    },
+    /// Start a new day: its crate, example slots and answers entry, wired
+    /// into the workspace and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Also save the puzzle statement to `day<N>/instructions.txt` and
+        /// its first example block to `examples/1.txt`
+        #[arg(short, long)]
+        fetch: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Monotonic {
+    /// Every step goes the same way
+    Strict,
+    /// Never both up and down
+    NonStrict,
+    /// Up and down as they like
+    Off,
+}
+
+impl From<Monotonic> for day2::Monotonic {
+    fn from(monotonic: Monotonic) -> Self {
+        match monotonic {
+            Monotonic::Strict => day2::Monotonic::Strict,
+            Monotonic::NonStrict => day2::Monotonic::NonStrict,
+            Monotonic::Off => day2::Monotonic::Off,
+        }
+    }
+}
+
+fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{day}"))
+}
+
+/// The requested days, or every day when none are given.
+fn select(days: &[u8], input: &Option<PathBuf>) -> Result<Vec<&'static Day>, String> {
+    if input.is_some() && days.len() != 1 {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    if days.is_empty() {
+        return Ok(days::DAYS.iter().collect());
+    }
+    days.iter()
+        .map(|&d| days::find(d).ok_or_else(|| format!("day {d} is not implemented")))
+        .collect()
+}
+
+/// The given input file, or else the day's cached input, fetched on a miss.
+fn read_input(day: &Day, input: &Option<PathBuf>) -> Result<String, String> {
+    match input {
+        Some(path) => load_input(&path.to_string_lossy())
+            .map_err(|e| format!("day {}: error loading {}: {e}", day.day, path.display())),
+        None => InputProvider::new(".")
+            .get(day.day)
+            .map_err(|e| format!("day {}: {e}", day.day)),
+    }
+}
+
+fn run(
+    days: Vec<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    render: Option<render::Format>,
+    animate: Option<render::Animation>,
+    every: usize,
+    output: record::Output,
+) -> Result<(), String> {
+    let selected = select(&days, &input)?;
+
+    let parts: &[u8] = match part {
+        Some(1) => &[1],
+        Some(_) => &[2],
+        None => &[1, 2],
+    };
+
+    if output != record::Output::Text && matches!(render, Some(render::Format::Ansi)) {
+        return Err(
+            "--render ansi writes to stdout, so it can't be used with --output".to_string(),
+        );
+    }
+
+    // Keep going after a broken day so a batch run reports every problem.
+    // Days run in parallel, but are reported in order as they finish
+    let mut failed = 0;
+    let mut records = Vec::new();
+    let solve = |day: &&Day| {
+        let contents = read_input(day, &input);
+        let solved = contents
+            .as_deref()
+            .map_err(Clone::clone)
+            .and_then(|contents| (day.run)(contents, parts).map_err(|e| e.to_string()));
+        (contents, solved)
+    };
+    parallel::each(&selected, solve, |day, result| {
+        // A day that panics fails like one whose input doesn't parse, but
+        // its input goes with it, so its records have no hash
+        let (contents, solved) = result.unwrap_or_else(|message| {
+            let failure = format!("day {}: {message}", day.day);
+            (Err(failure.clone()), Err(failure))
+        });
+        match output {
+            record::Output::Text => {
+                for part in solved.iter().flat_map(|solved| &solved.parts) {
+                    println!("Day {} part {}: {}", day.day, part.part, part.answer);
+                }
+            }
+            record::Output::Json => records.extend(record::records(
+                day.day,
+                parts,
+                contents.as_deref().ok(),
+                &solved,
+            )),
+            record::Output::Ndjson => {
+                for record in record::records(day.day, parts, contents.as_deref().ok(), &solved) {
+                    println!("{}", serde_json::to_string(&record).unwrap());
+                }
+            }
+        }
+        if let Err(e) = solved {
+            eprintln!("{e}");
+            failed += 1;
+            return;
+        }
+
+        if let (Some(format), Ok(contents)) = (render, &contents) {
+            let drawn = (day.render)(contents).map_err(|e| e.to_string());
+            let drawn = drawn.and_then(|canvas| match canvas {
+                Some(canvas) => render::output(day.day, &canvas, format),
+                None => {
+                    eprintln!("Day {} has nothing to render", day.day);
+                    Ok(())
+                }
+            });
+            if let Err(e) = drawn {
+                eprintln!("{e}");
+                failed += 1;
+            }
+        }
+
+        if let (Some(format), Ok(contents)) = (animate, &contents) {
+            let mut recorder = Recorder::new(every);
+            let replayed = (day.animate)(contents, &mut recorder).map_err(|e| e.to_string());
+            let replayed = replayed.and_then(|()| match recorder.is_empty() {
+                true => {
+                    eprintln!("Day {} has nothing to animate", day.day);
+                    Ok(())
+                }
+                false => render::output_animation(day.day, &recorder, format),
+            });
+            if let Err(e) = replayed {
+                eprintln!("{e}");
+                failed += 1;
+            }
+        }
+    });
+
+    if output == record::Output::Json {
+        println!("{}", serde_json::to_string_pretty(&records).unwrap());
+    }
+    if failed > 0 {
+        return Err(format!("{failed} day(s) failed"));
+    }
+    Ok(())
+}
+
+fn bench(
+    days: Vec<u8>,
+    iterations: usize,
+    input: Option<PathBuf>,
+    format: bench::Format,
+) -> Result<(), String> {
+    let selected = select(&days, &input)?;
+
+    let mut reports = Vec::new();
+    let mut failed = 0;
+    for day in selected {
+        let timings = read_input(day, &input)
+            .and_then(|contents| (day.bench)(&contents, iterations).map_err(|e| e.to_string()));
+
+        match timings {
+            Ok(timings) => reports.push(bench::Report::new(day.day, iterations, timings)),
+            Err(e) => {
+                eprintln!("{e}");
+                failed += 1;
+            }
+        }
+    }
+
+    print!("{}", bench::render(&reports, format));
+
+    if failed > 0 {
+        return Err(format!("{failed} day(s) failed"));
+    }
+    Ok(())
+}
+
+fn verify(days: Vec<u8>, answers: PathBuf) -> Result<(), String> {
+    let selected = select(&days, &None)?;
+    let answers =
+        Answers::load(&answers).map_err(|e| format!("error loading {}: {e}", answers.display()))?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in selected {
+        // Days with nothing recorded are still run, so they show up as missing
+        let mut inputs: Vec<&str> = answers.inputs(day.day).collect();
+        if inputs.is_empty() {
+            inputs.push("input.txt");
+        }
+
+        for name in inputs {
+            let path = day_dir(day.day).join(name);
+            let results = read_input(day, &Some(path))
+                .and_then(|contents| (day.run)(&contents, &[1, 2]).map_err(|e| e.to_string()));
+
+            let results = match results {
+                Ok(results) => results,
+                Err(e) => {
+                    eprintln!("{e}");
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for PartAnswer { part, answer, .. } in results.parts {
+                let label = format!("Day {} part {} ({})", day.day, part, name);
+                match answers.check(day.day, name, part, &answer) {
+                    answers::Check::Pass => {
+                        println!("{label}: ok");
+                        passed += 1;
+                    }
+                    answers::Check::Fail { expected } => {
+                        println!("{label}: FAIL\n  expected: {expected}\n  got:      {answer}");
+                        failed += 1;
+                    }
+                    answers::Check::Missing => {
+                        println!("{label}: missing (got {answer})");
+                        missing += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(format!("{failed} check(s) failed"));
+    }
+    Ok(())
+}
+
+fn extract_examples(day: u8, save: Vec<usize>) -> Result<(), String> {
+    let path = day_dir(day).join("instructions.txt");
+    let statement = load_input(&path.to_string_lossy())
+        .map_err(|e| format!("error loading {}: {e}", path.display()))?;
+    let blocks = examples::code_blocks(&statement);
+
+    if save.is_empty() {
+        for (i, block) in blocks.iter().enumerate() {
+            println!("Block {}:\n{}", i + 1, block);
+        }
+        return Ok(());
+    }
+
+    let dir = day_dir(day).join("examples");
+    fs::create_dir_all(&dir).map_err(|e| format!("error creating {}: {e}", dir.display()))?;
+
+    // Number new files after the ones already there
+    let first = (1..)
+        .find(|n| !dir.join(format!("{n}.txt")).exists())
+        .unwrap_or(1);
+    for (next, n) in (first..).zip(save) {
+        let block = blocks
+            .get(n.wrapping_sub(1))
+            .ok_or_else(|| format!("there is no block {n}, only {}", blocks.len()))?;
+        let file = dir.join(format!("{next}.txt"));
+        fs::write(&file, block).map_err(|e| format!("error writing {}: {e}", file.display()))?;
+        println!("Saved block {n} to {}", file.display());
+    }
+    println!(
+        "Add their answers to {}",
+        dir.join("answers.toml").display()
+    );
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    input: Option<PathBuf>,
+    history: PathBuf,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let [entry] = select(&[day], &input)?[..] else {
+                unreachable!("a single day was selected")
+            };
+            let contents = read_input(entry, &input)?;
+            let solved = (entry.run)(&contents, &[part]).map_err(|e| e.to_string())?;
+            solved.parts[0].answer.to_string()
+        }
+    };
+
+    println!("Day {} part {}: submitting {}", day, part, answer);
+    let response = Submitter::new(history)
+        .submit(day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("{}", response.message);
+
+    match response.outcome {
+        Outcome::Correct => Ok(()),
+        outcome => Err(format!("answer not accepted ({outcome:?})")),
+    }
+}
+
+fn differential(days: Vec<u8>, cases: usize, seed: u64, size: Option<usize>) -> Result<(), String> {
+    let checks: Vec<&Check> = days::DIFFERENTIALS
+        .iter()
+        .filter(|check| days.is_empty() || days.contains(&check.day))
+        .collect();
+    if checks.is_empty() {
+        return Err("no differential checks for those days".to_string());
+    }
+
+    // Panics are expected while shrinking and are reported as outputs, so
+    // keep the default hook from printing each one
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut failed = 0;
+    for check in checks {
+        let label = format!("Day {} {}", check.day, check.name);
+        match (check.run)(seed, cases, size.unwrap_or(check.max_size)) {
+            Ok(()) => println!("{label}: agree on {cases} inputs"),
+            Err(mismatch) => {
+                println!("{label}: DIFFER\n{mismatch}");
+                failed += 1;
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    if failed > 0 {
+        return Err(format!("{failed} pair(s) disagree"));
+    }
+    Ok(())
+}
+
+fn compare_lists(
+    input: Option<PathBuf>,
+    columns: Vec<usize>,
+    metrics: Vec<String>,
+    report: bool,
+) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| day_dir(1).join("input.txt"));
+    let file =
+        fs::File::open(&path).map_err(|e| format!("error opening {}: {e}", path.display()))?;
+    let lists =
+        day1::read_columns(BufReader::new(file)).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    let [left, right] = columns[..] else {
+        return Err("--columns takes two columns, e.g. `--columns 1,3`".to_string());
+    };
+    let column = |n: usize| {
+        lists
+            .get(n.wrapping_sub(1))
+            .ok_or_else(|| format!("there is no column {n}, only {}", lists.len()))
+    };
+    let (left, right) = (column(left)?, column(right)?);
+
+    for name in metrics {
+        let metric = day1::compare::metric(&name).expect("clap only accepts known metrics");
+        println!("{}: {}", metric.name(), metric.score(left, right));
+    }
+
+    if report {
+        let pairs = day1::compare::pair_diffs(left, right);
+        let mut out = BufWriter::new(io::stdout().lock());
+        let differing: Vec<_> = pairs.iter().filter(|pair| pair.diff != 0).collect();
+        let _ = writeln!(
+            out,
+            "{:>8}  {:>12}  {:>12}  {:>12}",
+            "rank", "left", "right", "diff"
+        );
+        for pair in &differing {
+            let _ = writeln!(out, "{pair}");
+        }
+        let _ = writeln!(out, "{} of {} pairs differ", differing.len(), pairs.len());
+    }
+    Ok(())
+}
+
+/// The rules `aoc reports` was asked for, refusing ones that would mark
+/// every report unsafe whatever its levels.
+fn report_rules(
+    min_step: i32,
+    max_step: i32,
+    plateaus: bool,
+    monotonic: Option<Monotonic>,
+) -> Result<day2::Rules, String> {
+    if min_step > max_step {
+        return Err(format!(
+            "--min-step {min_step} is more than --max-step {max_step}"
+        ));
+    }
+    let monotonic =
+        match (monotonic, plateaus) {
+            (Some(Monotonic::Strict), true) => return Err(
+                "--plateaus needs --monotonic non-strict or off, as strict rules out equal levels"
+                    .to_string(),
+            ),
+            (Some(monotonic), _) => monotonic,
+            (None, true) => Monotonic::NonStrict,
+            (None, false) => Monotonic::Strict,
+        };
+    Ok(day2::Rules::default()
+        .with_steps(min_step..=max_step)
+        .with_plateaus(plateaus)
+        .with_monotonic(monotonic.into()))
+}
+
+fn diagnose_reports(
+    input: Option<PathBuf>,
+    dampener: usize,
+    rules: day2::Rules,
+    unsafe_only: bool,
+) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| day_dir(2).join("input.txt"));
+    let contents = load_input(&path.to_string_lossy())
+        .map_err(|e| format!("error loading {}: {e}", path.display()))?;
+    let reports = day2::Day2::parse(&contents).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let (mut safe, mut dampened) = (0, 0);
+    for (line, report) in contents.lines().zip(&reports) {
+        let diagnosis = day2::diagnose(report, &rules, dampener);
+        match diagnosis {
+            day2::Diagnosis::Safe => safe += 1,
+            day2::Diagnosis::Dampened { .. } => dampened += 1,
+            day2::Diagnosis::Unsafe(_) => {}
+        }
+        if !(unsafe_only && diagnosis == day2::Diagnosis::Safe) {
+            let _ = writeln!(out, "{}: {diagnosis}", line.trim());
+        }
+    }
+    let _ = writeln!(
+        out,
+        "{safe} safe, {dampened} safe with the dampener, {} unsafe",
+        reports.len() - safe - dampened
+    );
+    Ok(())
+}
+
+fn generate(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<PathBuf>,
+) -> Result<(), String> {
+    let [entry] = select(&[day], &None)?[..] else {
+        unreachable!("a single day was selected")
+    };
+    let input = (entry.generate)(&mut Rng::new(seed), size)
+        .ok_or_else(|| format!("day {day} has no input generator"))?;
+
+    match output {
+        Some(path) => {
+            fs::write(&path, input).map_err(|e| format!("error writing {}: {e}", path.display()))
+        }
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    common::log::set_verbosity(cli.verbose);
+    if let Err(e) = parallel::set_jobs(cli.jobs) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+
+    let result = match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            render,
+            animate,
+            every,
+            output,
+        } => run(days, part, input, render, animate, every, output),
+        Command::Bench {
+            days,
+            iterations,
+            input,
+            format,
+        } => bench(days, iterations, input, format),
+        Command::Verify { days, answers } => verify(days, answers),
+        Command::Examples { day, save } => extract_examples(day, save),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            history,
+        } => submit(day, part, answer, input, history),
+        Command::Diff {
+            days,
+            cases,
+            seed,
+            size,
+        } => differential(days, cases, seed, size),
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => generate(day, seed, size, output),
+        Command::Lists {
+            input,
+            columns,
+            metric,
+            report,
+        } => compare_lists(input, columns, metric, report),
+        Command::Reports {
+            input,
+            dampener,
+            min_step,
+            max_step,
+            plateaus,
+            monotonic,
+            unsafe_only,
+        } => report_rules(min_step, max_step, plateaus, monotonic)
+            .and_then(|rules| diagnose_reports(input, dampener, rules, unsafe_only)),
+        Command::New { day, fetch } => scaffold::new_day(day, fetch),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
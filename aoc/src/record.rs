@@ -0,0 +1,76 @@
+use clap::ValueEnum;
+use common::{runner::Solved, Answer};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    /// `Day N part P: answer` lines for reading
+    Text,
+    /// A single array of records once every day has run
+    Json,
+    /// One record per line as each day finishes
+    Ndjson,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Ok,
+    Error,
+}
+
+/// One day and part of `aoc run`, for tools to read.
+#[derive(Serialize)]
+pub struct Record {
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: Option<Answer>,
+    parse_ns: Option<u64>,
+    time_ns: Option<u64>,
+    /// SHA-256 of the input, so results from different inputs aren't mixed up
+    input_sha256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// A record per part asked for: the answers, or the error for each part if
+/// the input couldn't be read or parsed.
+pub fn records(
+    day: u8,
+    parts: &[u8],
+    input: Option<&str>,
+    solved: &Result<Solved, String>,
+) -> Vec<Record> {
+    let input_sha256 = input.map(|input| format!("{:x}", Sha256::digest(input)));
+    match solved {
+        Ok(solved) => solved
+            .parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.part,
+                status: Status::Ok,
+                answer: Some(part.answer.clone()),
+                parse_ns: Some(solved.parse.as_nanos() as u64),
+                time_ns: Some(part.time.as_nanos() as u64),
+                input_sha256: input_sha256.clone(),
+                error: None,
+            })
+            .collect(),
+        Err(e) => parts
+            .iter()
+            .map(|&part| Record {
+                day,
+                part,
+                status: Status::Error,
+                answer: None,
+                parse_ns: None,
+                time_ns: None,
+                input_sha256: input_sha256.clone(),
+                error: Some(e.clone()),
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fs, path::PathBuf};
+
+use clap::ValueEnum;
+use common::{animate::Recorder, render::Canvas};
+
+/// Pixels per cell in image output.
+const SCALE: usize = 8;
+
+/// Where image renders are written.
+const DIR: &str = "renders";
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// Coloured text printed to the terminal
+    Ansi,
+    /// A binary PPM image
+    Ppm,
+    /// A PNG image
+    Png,
+    /// An SVG image, glyphs included
+    Svg,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Animation {
+    /// A looping animated GIF
+    Gif,
+    /// A directory of numbered PNG frames
+    Frames,
+}
+
+/// Prints `canvas`, or writes it to `renders/day<N>.<ext>` for images.
+pub fn output(day: u8, canvas: &Canvas, format: Format) -> Result<(), String> {
+    let (ext, bytes) = match format {
+        Format::Ansi => {
+            print!("{}", canvas.to_ansi());
+            return Ok(());
+        }
+        Format::Ppm => ("ppm", canvas.to_ppm(SCALE)),
+        Format::Png => ("png", canvas.to_png(SCALE)),
+        Format::Svg => ("svg", canvas.to_svg(SCALE * 2).into_bytes()),
+    };
+
+    let path = PathBuf::from(DIR).join(format!("day{day}.{ext}"));
+    fs::create_dir_all(DIR).map_err(|e| format!("error creating {DIR}: {e}"))?;
+    fs::write(&path, bytes).map_err(|e| format!("error writing {}: {e}", path.display()))?;
+    eprintln!("Day {} rendered to {}", day, path.display());
+    Ok(())
+}
+
+/// Writes the recorded frames to `renders/day<N>.gif` or, one PNG per frame,
+/// to `renders/day<N>/`.
+pub fn output_animation(day: u8, recorder: &Recorder, format: Animation) -> Result<(), String> {
+    let frames = recorder.len();
+    let path = match format {
+        Animation::Gif => {
+            let gif = recorder
+                .to_gif(SCALE / 2)
+                .map_err(|e| format!("error encoding day {day}: {e}"))?;
+            let path = PathBuf::from(DIR).join(format!("day{day}.gif"));
+            fs::create_dir_all(DIR).map_err(|e| format!("error creating {DIR}: {e}"))?;
+            fs::write(&path, gif).map_err(|e| format!("error writing {}: {e}", path.display()))?;
+            path
+        }
+        Animation::Frames => {
+            let path = PathBuf::from(DIR).join(format!("day{day}"));
+            recorder
+                .write_frames(&path, SCALE)
+                .map_err(|e| format!("error writing {}: {e}", path.display()))?;
+            path
+        }
+    };
+    eprintln!(
+        "Day {} animated in {} frames to {}",
+        day,
+        frames,
+        path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,266 @@
+//! `aoc new`: a new day's crate, from a template, wired into the workspace,
+//! the runner and the answers file.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use common::{examples, input::InputProvider};
+
+const CARGO_TOML: &str = r#"[package]
+name = "day{day}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = { path = "../common" }
+"#;
+
+const LIB_RS: &str = r#"use common::{Answer, AocError, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_lines: &Self::Input) -> Answer {
+        "not solved yet".into()
+    }
+
+    fn part2(_lines: &Self::Input) -> Answer {
+        "not solved yet".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day{day});
+}
+"#;
+
+const EXAMPLE_ANSWERS: &str = r#"["1.txt"]
+# part1 =
+# part2 =
+"#;
+
+/// What `cargo new` leaves in `src/main.rs`, as in the placeholder crates
+/// for days without a solution. Anything else there is left alone.
+const CARGO_NEW_MAIN: &str = "fn main() {\n    println!(\"Hello, world!\");\n}\n";
+
+/// Creates `day<N>` and registers it. Every file is worked out before any
+/// is written, so a failure leaves the tree as it was.
+pub fn new_day(day: u8, fetch: bool) -> Result<(), String> {
+    let dir = PathBuf::from(format!("day{day}"));
+    let registry = Path::new("aoc/src/days.rs");
+    if !registry.exists() {
+        return Err("run `aoc new` from the repository root".to_string());
+    }
+    if dir.join("src/lib.rs").exists() {
+        return Err(format!("{} already has a crate", dir.display()));
+    }
+    let main_rs = dir.join("src/main.rs");
+    let stub = read(&main_rs).ok();
+    if stub.as_deref().is_some_and(|main| main != CARGO_NEW_MAIN) {
+        return Err(format!(
+            "{} exists and isn't a placeholder",
+            main_rs.display()
+        ));
+    }
+
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    let mut writes = vec![
+        (dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (dir.join("src/lib.rs"), fill(LIB_RS)),
+    ];
+
+    let mut example = String::new();
+    if fetch {
+        let statement = InputProvider::new(".")
+            .statement(day)
+            .map_err(|e| format!("day {day}: {e}"))?;
+        // The first block is nearly always the example the parts work through
+        example = examples::code_blocks(&statement)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        writes.push((dir.join("instructions.txt"), statement));
+    }
+    let examples = dir.join("examples");
+    if !examples.join("answers.toml").exists() {
+        writes.push((examples.join("1.txt"), example));
+        writes.push((examples.join("answers.toml"), EXAMPLE_ANSWERS.to_string()));
+    }
+
+    for (path, line, key) in [
+        (
+            Path::new("Cargo.toml"),
+            format!("    \"day{day}\","),
+            crate_name as fn(&str) -> Option<u8>,
+        ),
+        (
+            Path::new("aoc/Cargo.toml"),
+            format!("day{day} = {{ path = \"../day{day}\" }}"),
+            dependency,
+        ),
+        (registry, format!("    day::<day{day}::Day{day}>(),"), entry),
+    ] {
+        let text = insert_line(&read(path)?, &line, day, key)
+            .ok_or_else(|| format!("can't find where day{day} goes in {}", path.display()))?;
+        writes.push((path.to_path_buf(), text));
+    }
+
+    let answers = Path::new("answers.toml");
+    writes.push((answers.to_path_buf(), insert_answers(&read(answers)?, day)));
+
+    for (path, contents) in &writes {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("error creating {}: {e}", parent.display()))?;
+        }
+        fs::write(path, contents).map_err(|e| format!("error writing {}: {e}", path.display()))?;
+    }
+    if stub.is_some() {
+        fs::remove_file(&main_rs)
+            .map_err(|e| format!("error removing {}: {e}", main_rs.display()))?;
+    }
+
+    println!("Created {} and added it to the runner", dir.display());
+    if fetch {
+        println!(
+            "Saved the statement to {}; `aoc examples {day}` lists its other blocks",
+            dir.join("instructions.txt").display()
+        );
+    }
+    println!(
+        "Fill in {} and the answers in answers.toml as you go",
+        examples.join("answers.toml").display()
+    );
+    Ok(())
+}
+
+/// The day of a workspace member line like `    "day4",`.
+fn crate_name(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("\"day")?
+        .strip_suffix("\",")?
+        .parse()
+        .ok()
+}
+
+/// The day of a dependency line like `day4 = { path = "../day4" }`.
+fn dependency(line: &str) -> Option<u8> {
+    line.strip_prefix("day")?.split_once(' ')?.0.parse().ok()
+}
+
+/// The day of a runner entry like `    day::<day4::Day4>(),`.
+fn entry(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("day::<day")?
+        .split_once(':')?
+        .0
+        .parse()
+        .ok()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("error reading {}: {e}", path.display()))
+}
+
+/// `text` with `line` added among the lines `key` gives a day for, keeping
+/// them in day order, and unchanged if `day` is already there. `None` if no
+/// line has a day to go by.
+fn insert_line(
+    text: &str,
+    line: &str,
+    day: u8,
+    key: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key(line)?)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Some(text.to_string());
+    }
+
+    let at = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => days.last()?.0 + 1,
+    };
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// `answers.toml` with an empty entry for `day`'s input, before the next
+/// day's entries, unless it has one already.
+fn insert_answers(text: &str, day: u8) -> String {
+    let header_day =
+        |line: &str| -> Option<u8> { line.strip_prefix("[day")?.split_once('.')?.0.parse().ok() };
+    if text.lines().any(|line| header_day(line) == Some(day)) {
+        return text.to_string();
+    }
+
+    let entry = format!("[day{day}.\"input.txt\"]\n# part1 =\n# part2 =\n\n");
+    match text
+        .lines()
+        .find(|&line| header_day(line).is_some_and(|d| d > day))
+    {
+        Some(next) => {
+            let at = text.find(&format!("{next}\n")).unwrap_or(text.len());
+            format!("{}{entry}{}", &text[..at], &text[at..])
+        }
+        None => format!("{}\n\n{}", text.trim_end(), entry.trim_end()) + "\n",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_days_in_order() {
+        let members = "members = [\n    \"common\",\n    \"day2\",\n    \"day10\",\n]\n";
+
+        assert_eq!(
+            insert_line(members, "    \"day4\",", 4, crate_name).unwrap(),
+            "members = [\n    \"common\",\n    \"day2\",\n    \"day4\",\n    \"day10\",\n]\n"
+        );
+        assert_eq!(
+            insert_line(members, "    \"day12\",", 12, crate_name).unwrap(),
+            "members = [\n    \"common\",\n    \"day2\",\n    \"day10\",\n    \"day12\",\n]\n"
+        );
+        assert_eq!(
+            insert_line(members, "    \"day2\",", 2, crate_name).as_deref(),
+            Some(members)
+        );
+        assert_eq!(
+            insert_line("members = []\n", "    \"day1\",", 1, crate_name),
+            None
+        );
+    }
+
+    #[test]
+    fn adds_an_answers_entry_once() {
+        let answers = "[day2.\"input.txt\"]\npart1 = 1\n\n[day9.\"input.txt\"]\npart1 = 2\n";
+        let entry = "[day4.\"input.txt\"]\n# part1 =\n# part2 =\n\n";
+
+        assert_eq!(
+            insert_answers(answers, 4),
+            answers.replace("[day9", &format!("{entry}[day9"))
+        );
+        assert_eq!(
+            insert_answers(answers, 12),
+            format!("{answers}\n[day12.\"input.txt\"]\n# part1 =\n# part2 =\n")
+        );
+        assert_eq!(insert_answers(answers, 9), answers);
+    }
+}
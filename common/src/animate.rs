@@ -0,0 +1,325 @@
+//! Replays of simulations: a [`Recorder`] the simulation pushes frames into,
+//! exported as an animated GIF or as numbered PNG frames.
+
+use std::{borrow::Cow, collections::HashMap, fs, io, path::Path};
+
+use crate::{
+    geom::Point,
+    render::{Canvas, Cell, Rgb},
+};
+
+/// How long each frame shows, in hundredths of a second.
+const FRAME_DELAY: u16 = 4;
+
+/// How long the final frame holds before the animation loops.
+const LAST_FRAME_DELAY: u16 = 200;
+
+/// Collects frames from a simulation, keeping one in every `every` offered
+/// so long runs stay a manageable size. Only the first kept frame is held
+/// whole; after it each one is stored as the rectangle that changed, and
+/// the GIF palette grows as colours turn up, so memory follows how much
+/// the simulation changes rather than frames times canvas size.
+pub struct Recorder {
+    every: usize,
+    offered: usize,
+    last_kept: bool,
+    first: Option<Canvas>,
+    latest: Option<Canvas>,
+    changes: Vec<Change>,
+    palette: Palette,
+}
+
+/// A kept frame after the first: the cells in the smallest rectangle that
+/// differs from the frame before, row by row.
+struct Change {
+    top_left: Point,
+    width: usize,
+    cells: Vec<Cell>,
+}
+
+impl Change {
+    fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    fn apply(&self, canvas: &mut Canvas) {
+        for (i, &cell) in self.cells.iter().enumerate() {
+            let pos = Point::new(
+                self.top_left.row + i / self.width,
+                self.top_left.col + i % self.width,
+            );
+            if let Some(old) = canvas.cell_mut(pos) {
+                *old = cell;
+            }
+        }
+    }
+}
+
+impl Recorder {
+    pub fn new(every: usize) -> Self {
+        Recorder {
+            every: every.max(1),
+            offered: 0,
+            last_kept: false,
+            first: None,
+            latest: None,
+            changes: Vec::new(),
+            palette: Palette::default(),
+        }
+    }
+
+    /// Offers a frame. `draw` is only called for frames that are kept, so
+    /// skipped ones cost nothing.
+    pub fn frame(&mut self, draw: impl FnOnce() -> Canvas) {
+        self.last_kept = self.offered.is_multiple_of(self.every);
+        if self.last_kept {
+            self.keep(draw());
+        }
+        self.offered += 1;
+    }
+
+    /// Offers the final state, which is always kept unless it was the last
+    /// frame offered and already kept.
+    pub fn finish(&mut self, draw: impl FnOnce() -> Canvas) {
+        if !self.last_kept {
+            self.keep(draw());
+            self.last_kept = true;
+        }
+    }
+
+    fn keep(&mut self, canvas: Canvas) {
+        match &self.latest {
+            None => {
+                let whole = (
+                    Point::new(0, 0),
+                    Point::new(canvas.height() - 1, canvas.width() - 1),
+                );
+                self.palette.add(cells_in(&canvas, whole));
+                self.first = Some(canvas.clone());
+            }
+            Some(latest) => {
+                // An unchanged frame still needs a cell to carry its delay
+                let (top_left, bottom_right) = changed(latest, &canvas).unwrap_or_default();
+                let cells: Vec<Cell> = cells_in(&canvas, (top_left, bottom_right)).collect();
+                self.palette.add(cells.iter().copied());
+                self.changes.push(Change {
+                    top_left,
+                    width: bottom_right.col - top_left.col + 1,
+                    cells,
+                });
+            }
+        }
+        self.latest = Some(canvas);
+    }
+
+    /// How many frames were kept.
+    pub fn len(&self) -> usize {
+        self.first.iter().count() + self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first.is_none()
+    }
+
+    /// Every kept frame, rebuilt one at a time from the changes.
+    pub fn frames(&self) -> impl Iterator<Item = Canvas> + '_ {
+        let mut next = self.first.clone();
+        let mut changes = self.changes.iter();
+        std::iter::from_fn(move || {
+            let frame = next.take()?;
+            next = changes.next().map(|change| {
+                let mut canvas = frame.clone();
+                change.apply(&mut canvas);
+                canvas
+            });
+            Some(frame)
+        })
+    }
+
+    /// A looping GIF of every kept frame with each cell drawn as a
+    /// `scale`-pixel square. After the first, frames only store the part
+    /// that changed.
+    pub fn to_gif(&self, scale: usize) -> Result<Vec<u8>, gif::EncodingError> {
+        let scale = scale.max(1);
+        let Some(first) = &self.first else {
+            return Ok(Vec::new());
+        };
+
+        let mut out = Vec::new();
+        let mut encoder = gif::Encoder::new(
+            &mut out,
+            (first.width() * scale) as u16,
+            (first.height() * scale) as u16,
+            &self.palette.rgb(),
+        )?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        let whole = Change {
+            top_left: Point::new(0, 0),
+            width: first.width(),
+            cells: cells_in(
+                first,
+                (
+                    Point::new(0, 0),
+                    Point::new(first.height() - 1, first.width() - 1),
+                ),
+            )
+            .collect(),
+        };
+        let frames = self.len();
+        for (i, change) in std::iter::once(&whole).chain(&self.changes).enumerate() {
+            let mut buffer = Vec::with_capacity(change.cells.len() * scale * scale);
+            for row in change.cells.chunks(change.width) {
+                for _ in 0..scale {
+                    for cell in row {
+                        let index = self.palette.index(cell.bg);
+                        buffer.extend(std::iter::repeat_n(index, scale));
+                    }
+                }
+            }
+
+            let frame = gif::Frame {
+                delay: match i + 1 == frames {
+                    true => LAST_FRAME_DELAY,
+                    false => FRAME_DELAY,
+                },
+                dispose: gif::DisposalMethod::Keep,
+                left: (change.top_left.col * scale) as u16,
+                top: (change.top_left.row * scale) as u16,
+                width: (change.width * scale) as u16,
+                height: (change.height() * scale) as u16,
+                buffer: Cow::Owned(buffer),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame)?;
+        }
+
+        drop(encoder);
+        Ok(out)
+    }
+
+    /// Writes every kept frame to `dir` as `frame00001.png` onwards,
+    /// returning how many were written.
+    pub fn write_frames(&self, dir: &Path, scale: usize) -> io::Result<usize> {
+        fs::create_dir_all(dir)?;
+        for (i, canvas) in self.frames().enumerate() {
+            fs::write(
+                dir.join(format!("frame{:05}.png", i + 1)),
+                canvas.to_png(scale),
+            )?;
+        }
+        Ok(self.len())
+    }
+}
+
+/// The cells from `top_left` to `bottom_right` inclusive, row by row.
+fn cells_in(
+    canvas: &Canvas,
+    (top_left, bottom_right): (Point, Point),
+) -> impl Iterator<Item = Cell> + '_ {
+    (top_left.row..=bottom_right.row).flat_map(move |row| {
+        (top_left.col..=bottom_right.col)
+            .map(move |col| *canvas.cell(Point::new(row, col)).unwrap())
+    })
+}
+
+/// The corners of the smallest rectangle holding every cell that differs
+/// between the two frames, or `None` if they are the same.
+fn changed(before: &Canvas, after: &Canvas) -> Option<(Point, Point)> {
+    let mut corners: Option<(Point, Point)> = None;
+    for row in 0..after.height() {
+        for col in 0..after.width() {
+            let pos = Point::new(row, col);
+            if before.cell(pos) == after.cell(pos) {
+                continue;
+            }
+            let (top_left, bottom_right) = corners.get_or_insert((pos, pos));
+            top_left.col = top_left.col.min(col);
+            bottom_right.row = row;
+            bottom_right.col = bottom_right.col.max(col);
+        }
+    }
+    corners
+}
+
+/// The GIF colour table: every background colour used, up to 256, with any
+/// beyond that drawn in the nearest colour already in the table.
+#[derive(Default)]
+struct Palette {
+    colours: Vec<Rgb>,
+    indices: HashMap<Rgb, u8>,
+}
+
+impl Palette {
+    fn add(&mut self, cells: impl IntoIterator<Item = Cell>) {
+        for Cell { bg: colour, .. } in cells {
+            if self.indices.contains_key(&colour) {
+                continue;
+            }
+            let index = match self.colours.len() {
+                n if n < 256 => {
+                    self.colours.push(colour);
+                    n
+                }
+                _ => nearest(&self.colours, colour),
+            };
+            self.indices.insert(colour, index as u8);
+        }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.colours
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+
+    fn index(&self, colour: Rgb) -> u8 {
+        self.indices[&colour]
+    }
+}
+
+fn nearest(colours: &[Rgb], Rgb(r, g, b): Rgb) -> usize {
+    let distance = |&Rgb(r2, g2, b2): &Rgb| {
+        [(r, r2), (g, g2), (b, b2)]
+            .map(|(x, y)| (x as i32 - y as i32).pow(2))
+            .iter()
+            .sum::<i32>()
+    };
+    (0..colours.len())
+        .min_by_key(|&i| distance(&colours[i]))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{render::map_cell, Grid};
+
+    #[test]
+    fn skips_frames_but_keeps_the_last() {
+        let grid = Grid::parse("...\n...\n").unwrap();
+        let mut canvas = Canvas::from_grid(&grid, map_cell);
+        let mut recorder = Recorder::new(3);
+
+        let steps: Vec<Point> = grid.positions().collect();
+        for &pos in &steps {
+            canvas.highlight([pos], Rgb::YELLOW);
+            recorder.frame(|| canvas.clone());
+        }
+        recorder.finish(|| canvas.clone());
+
+        // Frames 0 and 3 of 6, plus the final state
+        let frames: Vec<Canvas> = recorder.frames().collect();
+        assert_eq!(recorder.len(), 3);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[2], canvas);
+        assert_eq!(
+            changed(&frames[0], &frames[1]),
+            Some((Point::new(0, 0), Point::new(1, 2)))
+        );
+
+        let gif = recorder.to_gif(2).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+}
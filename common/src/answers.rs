@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, io, path::Path};
+
+use serde::Deserialize;
+use toml::Value;
+
+use crate::{load_input, solution::Settings, Answer};
+
+/// Known-good answers per day, input file and part, read from a TOML file
+/// laid out as
+///
+/// ```toml
+/// [day1."input.txt"]
+/// part1 = 2375403
+/// part2 = 23082277
+/// ```
+///
+/// Input files are relative to the day's directory. Text answers are strings.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Parts>>,
+}
+
+/// The recorded answers for one input; either part may be left out. Only
+/// examples have `settings`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Parts {
+    part1: Option<Value>,
+    part2: Option<Value>,
+    #[serde(default)]
+    settings: Settings,
+}
+
+impl Parts {
+    pub(crate) fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// The answer to `part`, formatted the way `Answer` displays it.
+    pub(crate) fn get(&self, part: u8) -> Option<String> {
+        let value = match part {
+            1 => self.part1.as_ref()?,
+            _ => self.part2.as_ref()?,
+        };
+        Some(match value {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    }
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = load_input(&path.to_string_lossy())?;
+        Answers::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// The input files with recorded answers for `day`, in name order.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = &str> {
+        self.days
+            .get(&format!("day{day}"))
+            .into_iter()
+            .flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+
+    /// The recorded answer, formatted the way `Answer` displays it.
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<String> {
+        self.days.get(&format!("day{day}"))?.get(input)?.get(part)
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &Answer) -> Check {
+        match self.expected(day, input, part) {
+            Some(expected) if expected == answer.to_string() => Check::Pass,
+            Some(expected) => Check::Fail { expected },
+            None => Check::Missing,
+        }
+    }
+}
+
+/// How an answer compares with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_numbers_and_text() {
+        let answers = Answers::parse(
+            r#"
+            [day17."input.txt"]
+            part1 = "1,3,7"
+            part2 = 202367025818154
+
+            [day17."examples/1.txt"]
+            part1 = "4,6,3"
+            "#,
+        )
+        .unwrap();
+
+        let inputs: Vec<_> = answers.inputs(17).collect();
+        assert_eq!(inputs, ["examples/1.txt", "input.txt"]);
+        assert_eq!(
+            answers.check(17, "input.txt", 1, &"1,3,7".into()),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check(17, "input.txt", 2, &202367025818154_i64.into()),
+            Check::Pass
+        );
+        assert_eq!(
+            answers.check(17, "input.txt", 2, &5.into()),
+            Check::Fail {
+                expected: "202367025818154".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(17, "examples/1.txt", 2, &5.into()),
+            Check::Missing
+        );
+        assert_eq!(answers.inputs(1).count(), 0);
+    }
+}
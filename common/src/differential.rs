@@ -0,0 +1,191 @@
+//! Differential testing: run a slow, obviously-right implementation and a
+//! fast one on the same generated inputs, and when they disagree shrink the
+//! input to the smallest one that still shows it.
+
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{error::panic_message, random::Rng};
+
+/// Two implementations of the same thing that should always agree.
+pub trait Differential {
+    const DAY: u8;
+
+    /// What is being compared, e.g. `guard walk`.
+    const NAME: &'static str;
+
+    /// Inputs are generated at sizes up to this, small ones first.
+    const MAX_SIZE: usize;
+
+    type Input: fmt::Display;
+
+    type Output: PartialEq + fmt::Debug;
+
+    fn reference(input: &Self::Input) -> Self::Output;
+
+    fn fast(input: &Self::Input) -> Self::Output;
+
+    /// A random input both implementations can run on (they must finish).
+    fn generate(rng: &mut Rng, size: usize) -> Self::Input;
+
+    /// Inputs a step smaller than `input`, most promising first. Every one
+    /// must still be something both implementations can run on.
+    fn shrink(input: &Self::Input) -> Vec<Self::Input>;
+}
+
+/// A `Differential` with its types erased, so pairs from different days
+/// can share a registry.
+pub struct Check {
+    pub day: u8,
+    pub name: &'static str,
+    pub max_size: usize,
+    pub run: fn(u64, usize, usize) -> Result<(), Mismatch>,
+}
+
+pub const fn check<D: Differential>() -> Check {
+    Check {
+        day: D::DAY,
+        name: D::NAME,
+        max_size: D::MAX_SIZE,
+        run: run::<D>,
+    }
+}
+
+/// The smallest input found on which the two implementations differ, and
+/// the seed and size of the generated input it was shrunk from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub reference: String,
+    pub fast: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "seed {}, size {}, shrunk to:", self.seed, self.size)?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "  reference: {}", self.reference)?;
+        write!(f, "  fast:      {}", self.fast)
+    }
+}
+
+/// Outputs, or the message the implementation panicked with. A panic on
+/// one side only is a disagreement like any other.
+type Outcome<O> = Result<O, String>;
+
+fn outcome<I, O>(f: fn(&I) -> O, input: &I) -> Outcome<O> {
+    panic::catch_unwind(AssertUnwindSafe(|| f(input))).map_err(|payload| panic_message(&*payload))
+}
+
+fn describe<O: fmt::Debug>(outcome: &Outcome<O>) -> String {
+    match outcome {
+        Ok(output) => format!("{output:?}"),
+        Err(message) => message.clone(),
+    }
+}
+
+/// Tries `cases` inputs from consecutive seeds starting at `seed`, growing
+/// from size 1 to `max_size`, and shrinks the first one they disagree on.
+fn run<D: Differential>(seed: u64, cases: usize, max_size: usize) -> Result<(), Mismatch> {
+    let differs = |input: &D::Input| {
+        let (reference, fast) = (outcome(D::reference, input), outcome(D::fast, input));
+        (reference != fast).then_some((reference, fast))
+    };
+
+    for case in 0..cases {
+        let seed = seed.wrapping_add(case as u64);
+        let size = 1 + case * max_size.max(1) / cases.max(1);
+        let mut input = D::generate(&mut Rng::new(seed), size);
+        let Some(mut outputs) = differs(&input) else {
+            continue;
+        };
+
+        // Greedily take the first smaller input that still disagrees until
+        // none does
+        while let Some((smaller, smaller_outputs)) = D::shrink(&input)
+            .into_iter()
+            .find_map(|candidate| differs(&candidate).map(|outputs| (candidate, outputs)))
+        {
+            input = smaller;
+            outputs = smaller_outputs;
+        }
+
+        return Err(Mismatch {
+            seed,
+            size,
+            input: input.to_string(),
+            reference: describe(&outputs.0),
+            fast: describe(&outputs.1),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums a list, with a "fast" version that forgets numbers over 100.
+    struct Sum;
+
+    struct List(Vec<u32>);
+
+    impl fmt::Display for List {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+
+    impl Differential for Sum {
+        const DAY: u8 = 0;
+        const NAME: &'static str = "sum";
+        const MAX_SIZE: usize = 20;
+
+        type Input = List;
+        type Output = u32;
+
+        fn reference(list: &List) -> u32 {
+            list.0.iter().sum()
+        }
+
+        fn fast(list: &List) -> u32 {
+            list.0.iter().filter(|&&n| n <= 100).sum()
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> List {
+            List((0..size).map(|_| rng.range(0..1000) as u32).collect())
+        }
+
+        fn shrink(list: &List) -> Vec<List> {
+            let mut smaller = vec![];
+            for i in 0..list.0.len() {
+                let mut without = list.0.clone();
+                without.remove(i);
+                smaller.push(List(without));
+            }
+            for i in 0..list.0.len() {
+                let mut halved = list.0.clone();
+                halved[i] /= 2;
+                if halved[i] != list.0[i] {
+                    smaller.push(List(halved));
+                }
+            }
+            smaller
+        }
+    }
+
+    #[test]
+    fn shrinks_to_the_smallest_disagreement() {
+        let check = check::<Sum>();
+        let mismatch = (check.run)(1, 100, check.max_size).unwrap_err();
+
+        // Halving stops at the first value over 100 that halves to 100 or less
+        assert_eq!(mismatch.input.len(), "[101]".len());
+        assert!((101..=201).contains(&mismatch.input[1..4].parse::<u32>().unwrap()));
+        assert_eq!(mismatch.fast, "0");
+    }
+}
@@ -0,0 +1,132 @@
+use std::{any::Any, error::Error, fmt, str::FromStr};
+
+/// An error in a day's input, pointing at where it went wrong.
+///
+/// Line and column numbers are 1-based, as an editor would show them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            day: None,
+            line: None,
+            column: None,
+            text: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error on line `index` (0-based, as from `enumerate`) whose text
+    /// can't be shown, such as one that couldn't be read.
+    pub fn on_line(index: usize, message: impl Into<String>) -> Self {
+        AocError {
+            line: Some(index + 1),
+            ..AocError::new(message)
+        }
+    }
+
+    /// An error about the whole of line `index` (0-based, as from `enumerate`).
+    pub fn invalid_line(index: usize, line: &str, message: impl Into<String>) -> Self {
+        AocError {
+            text: Some(line.to_string()),
+            ..AocError::on_line(index, message)
+        }
+    }
+
+    /// An error about `token`, which must be a slice of `line`.
+    pub fn invalid_token(
+        index: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        AocError {
+            column: Some(column_of(line, token)),
+            text: Some(token.to_string()),
+            ..AocError::invalid_line(index, line, message)
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+/// What a caught panic said, as `panicked: <message>`.
+pub(crate) fn panic_message(payload: &dyn Any) -> String {
+    let message = match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message.as_str(),
+        _ => "a non-string payload",
+    };
+    format!("panicked: {message}")
+}
+
+/// 1-based character column at which `token`, a slice of `line`, starts.
+fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(line.as_ptr() as usize)
+        .filter(|&offset| offset <= line.len())
+        .unwrap_or(0);
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `token` (a slice of line `index`) as a number.
+pub fn parse_token<T: FromStr>(index: usize, line: &str, token: &str) -> Result<T, AocError> {
+    token
+        .parse()
+        .map_err(|_| AocError::invalid_token(index, line, token, "invalid number"))
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {}", day)),
+            self.line.map(|line| format!("line {}", line)),
+            self.column.map(|column| format!("column {}", column)),
+        ];
+        let location: Vec<String> = location.into_iter().flatten().collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, ": `{}`", text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_offending_token() {
+        let input = "3   4\n4   x3\n";
+        let (index, line) = input.lines().enumerate().nth(1).unwrap();
+        let token = line.split_whitespace().nth(1).unwrap();
+
+        let err = parse_token::<i32>(index, line, token).unwrap_err();
+
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.column, Some(5));
+        assert_eq!(
+            err.with_day(1).to_string(),
+            "day 1, line 2, column 5: invalid number: `x3`"
+        );
+    }
+}
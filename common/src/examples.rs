@@ -0,0 +1,202 @@
+use std::{collections::BTreeMap, fmt::Write, io, path::Path};
+
+use crate::{answers::Parts, load_input, solution::Settings, Solution};
+
+/// An example input from a day's `examples/` directory. The directory holds
+/// the inputs as text files and an `answers.toml` naming each one with its
+/// expected answers, either of which may be left out, and any settings the
+/// statement changes for it:
+///
+/// ```toml
+/// ["1.txt"]
+/// part1 = 22
+/// part2 = "6,1"
+/// settings = { size = 7, bytes = 12 }
+/// ```
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    expected: Parts,
+}
+
+impl Example {
+    /// The expected answer to `part`, formatted the way `Answer` displays it.
+    pub fn expected(&self, part: u8) -> Option<String> {
+        self.expected.get(part)
+    }
+
+    pub fn settings(&self) -> &Settings {
+        self.expected.settings()
+    }
+}
+
+/// Loads every example listed in `dir/answers.toml`, in name order.
+pub fn load(dir: &Path) -> io::Result<Vec<Example>> {
+    let answers = load_input(&dir.join("answers.toml").to_string_lossy())?;
+    let answers: BTreeMap<String, Parts> =
+        toml::from_str(&answers).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    answers
+        .into_iter()
+        .map(|(name, expected)| {
+            let input = load_input(&dir.join(&name).to_string_lossy())?;
+            Ok(Example {
+                name,
+                input,
+                expected,
+            })
+        })
+        .collect()
+}
+
+/// Runs `S` on every example in `dir` and panics listing each mismatch.
+/// Only parts with an expected answer are run.
+pub fn check<S: Solution>(dir: &Path) {
+    let examples = load(dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display()));
+    assert!(!examples.is_empty(), "{} lists no examples", dir.display());
+
+    let mut failures = String::new();
+    for example in &examples {
+        let configured = S::parse(&example.input).and_then(|mut input| {
+            S::configure(&mut input, example.settings())?;
+            Ok(input)
+        });
+        let input = match configured {
+            Ok(input) => input,
+            Err(e) => {
+                let _ = writeln!(failures, "{}: {}", example.name, e.with_day(S::DAY));
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let Some(expected) = example.expected(part) else {
+                continue;
+            };
+            let answer = match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            };
+            if answer.to_string() != expected {
+                let _ = writeln!(
+                    failures,
+                    "{} part {}: expected {}, got {}",
+                    example.name, part, expected, answer
+                );
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "day {} examples failed:\n{failures}",
+        S::DAY
+    );
+}
+
+/// The blocks of a puzzle statement that look like puzzle input: runs of
+/// lines that don't end like a sentence. Worked examples drawn over the
+/// input come out too, so callers pick the blocks they want.
+pub fn code_blocks(statement: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut block = String::new();
+
+    for line in statement.lines() {
+        let prose = line.trim().is_empty()
+            || line.starts_with("--- ")
+            || line.trim_end().ends_with(['.', ':', '?', '!']);
+
+        if !prose {
+            block.push_str(line);
+            block.push('\n');
+        } else if !block.is_empty() {
+            blocks.push(std::mem::take(&mut block));
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// The text of a puzzle page's statement, laid out like the page: one line
+/// per paragraph with a blank line after, and example blocks line for line
+/// so [`code_blocks`] can find them. Both parts are kept once part one is
+/// solved.
+pub fn statement_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let end = rest[start..]
+            .find("</article>")
+            .map_or(rest.len(), |end| start + end);
+        let mut tags = rest[start..end].split('<');
+        text.push_str(tags.next().unwrap_or_default());
+        for tag in tags {
+            let (name, after) = tag.split_once('>').unwrap_or((tag, ""));
+            match name.split_whitespace().next().unwrap_or_default() {
+                "/h2" => text.push('\n'),
+                "/p" | "/ul" => text.push_str("\n\n"),
+                "li" => text.push_str("- "),
+                "/li" => text.push('\n'),
+                _ => {}
+            }
+            text.push_str(after);
+        }
+        text.push_str("\n\n");
+        rest = &rest[end..];
+    }
+
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    lines.dedup_by(|next, line| next.is_empty() && line.is_empty());
+    let mut text = lines.join("\n").trim().to_string();
+    text.push('\n');
+    text
+}
+
+/// Adds a test checking a day's `Solution` against its `examples/` directory.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples() {
+            $crate::examples::check::<$solution>(::std::path::Path::new(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/examples"
+            )));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_input_between_prose() {
+        let statement =
+            "--- Day 0: Test ---\nFor example:\n\n3   4\n4   3\nThe total is 2.\n\n#.#\n";
+
+        assert_eq!(code_blocks(statement), ["3   4\n4   3\n", "#.#\n"]);
+    }
+
+    #[test]
+    fn reads_the_statement_off_the_page() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 0: Test ---</h2>\
+                    <p>Pairs, <em>for example</em>:</p>\n<pre><code>3   4\n4 &lt; 5\n</code></pre>\n\
+                    <p>The total is 2.</p></article><p>Answer: <input/></p></main>";
+
+        let text = statement_text(html);
+        assert_eq!(
+            text,
+            "--- Day 0: Test ---\nPairs, for example:\n\n3   4\n4 < 5\n\nThe total is 2.\n"
+        );
+        assert_eq!(code_blocks(&text), ["3   4\n4 < 5\n"]);
+    }
+}
@@ -0,0 +1,231 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A cell position. Rows grow downwards and columns to the right, matching
+/// how the puzzle maps are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// An offset between two points, in rows and columns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub dr: isize,
+    pub dc: isize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Point { row, col }
+    }
+
+    /// The point `offset` away, unless that would leave the first quadrant.
+    /// Grids additionally check the far edges with `Grid::step`.
+    pub fn offset(self, offset: impl Into<Vector>) -> Option<Point> {
+        let offset = offset.into();
+        Some(Point {
+            row: self.row.checked_add_signed(offset.dr)?,
+            col: self.col.checked_add_signed(offset.dc)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector {
+            dr: self.row as isize - other.row as isize,
+            dc: self.col as isize - other.col as isize,
+        }
+    }
+}
+
+impl Vector {
+    pub const fn new(dr: isize, dc: isize) -> Self {
+        Vector { dr, dc }
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Vector::new(self.dc, -self.dr)
+    }
+
+    /// Rotates 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Vector::new(-self.dc, self.dr)
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.dr.unsigned_abs() + self.dc.unsigned_abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dr + other.dr, self.dc + other.dc)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dr, -self.dc)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: isize) -> Vector {
+        Vector::new(self.dr * n, self.dc * n)
+    }
+}
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction4::Up => Vector::new(-1, 0),
+            Direction4::Right => Vector::new(0, 1),
+            Direction4::Down => Vector::new(1, 0),
+            Direction4::Left => Vector::new(0, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Vector {
+    fn from(direction: Direction4) -> Self {
+        direction.vector()
+    }
+}
+
+/// One of the eight compass directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Rotates 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::Up => Vector::new(-1, 0),
+            Direction8::UpRight => Vector::new(-1, 1),
+            Direction8::Right => Vector::new(0, 1),
+            Direction8::DownRight => Vector::new(1, 1),
+            Direction8::Down => Vector::new(1, 0),
+            Direction8::DownLeft => Vector::new(1, -1),
+            Direction8::Left => Vector::new(0, -1),
+            Direction8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(direction: Direction8) -> Self {
+        direction.vector()
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_matches_vector_rotation() {
+        for dir in Direction4::ALL {
+            assert_eq!(dir.turn_right().vector(), dir.vector().turn_right());
+            assert_eq!(dir.turn_left().vector(), dir.vector().turn_left());
+            assert_eq!(dir.opposite().vector(), -dir.vector());
+            assert_eq!(Direction8::from(dir).vector(), dir.vector());
+        }
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().vector(), -dir.vector());
+        }
+    }
+
+    #[test]
+    fn stepping_checks_the_top_and_left_edges() {
+        let origin = Point::new(0, 0);
+
+        assert_eq!(origin.offset(Direction4::Up), None);
+        assert_eq!(origin.offset(Direction4::Left), None);
+        assert_eq!(origin.offset(Direction8::DownRight), Some(Point::new(1, 1)));
+        assert_eq!(Point::new(3, 1).manhattan(Point::new(1, 4)), 5);
+        assert_eq!(Point::new(3, 1) - Point::new(1, 4), Vector::new(2, -3));
+    }
+}
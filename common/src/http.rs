@@ -0,0 +1,90 @@
+use std::{error, fmt, time::Duration};
+
+pub(crate) const USER_AGENT: &str = "github.com/A1DS19/advent-of-code-2024 via the aoc runner";
+
+/// The two requests the site needs, both authenticated with the session
+/// cookie. A trait so the input and submission clients can be tested
+/// without the network.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError>;
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, HttpError>;
+}
+
+/// The real client.
+pub struct Client {
+    agent: ureq::Agent,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+}
+
+impl Http for Client {
+    fn get(&self, url: &str, session: &str) -> Result<String, HttpError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        body(response)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, HttpError> {
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form);
+        body(response)
+    }
+}
+
+fn body(response: Result<ureq::Response, ureq::Error>) -> Result<String, HttpError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| HttpError::Transport(e.to_string())),
+        Err(ureq::Error::Status(status, response)) => Err(HttpError::Status(
+            status,
+            response.into_string().unwrap_or_default(),
+        )),
+        Err(e) => Err(HttpError::Transport(e.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpError {
+    /// The server answered with a non-success status and this body.
+    Status(u16, String),
+    /// The request never got an answer.
+    Transport(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Status(status, body) => write!(f, "status {}: {}", status, body.trim()),
+            HttpError::Transport(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for HttpError {}
@@ -0,0 +1,223 @@
+use std::{
+    env, error, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    examples,
+    http::{Client, Http, HttpError},
+    load_input,
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com/2024";
+
+/// Environment variable holding the adventofcode.com session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Puzzle inputs cached as `<root>/day<N>/input.txt`, fetched from the site
+/// the first time a day is asked for and read from disk ever after.
+pub struct InputProvider {
+    root: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    http: Box<dyn Http>,
+}
+
+impl InputProvider {
+    /// A provider caching under `root`, using the session token from
+    /// `AOC_SESSION` or the config file (see [`session_token`]).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputProvider {
+            root: root.into(),
+            base_url: BASE_URL.to_string(),
+            session: session_token(),
+            http: Box::new(Client::default()),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        self
+    }
+
+    pub fn with_http(mut self, http: impl Http + 'static) -> Self {
+        self.http = Box::new(http);
+        self
+    }
+
+    /// Where `day`'s input is cached.
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root.join(format!("day{day}")).join("input.txt")
+    }
+
+    /// The cached input for `day`, fetching and storing it on a miss.
+    pub fn get(&self, day: u8) -> Result<String, InputError> {
+        let path = self.path(day);
+        match load_input(&path.to_string_lossy()) {
+            Ok(input) => return Ok(input),
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(InputError::Io(path, e)),
+            Err(_) => {}
+        }
+
+        let session = self
+            .session
+            .as_deref()
+            .ok_or(InputError::NoSession(path.clone()))?;
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let input = self
+            .http
+            .get(&url, session)
+            .map_err(|e| InputError::Http(url, e))?;
+        store(&path, &input).map_err(|e| InputError::Io(path, e))?;
+        Ok(input)
+    }
+
+    /// The puzzle statement for `day` as text (see
+    /// [`statement_text`](crate::examples::statement_text)). Part one needs
+    /// no session; part two only shows once part one is solved on it.
+    pub fn statement(&self, day: u8) -> Result<String, InputError> {
+        let url = format!("{}/day/{}", self.base_url, day);
+        let html = self
+            .http
+            .get(&url, self.session.as_deref().unwrap_or_default())
+            .map_err(|e| InputError::Http(url, e))?;
+        Ok(examples::statement_text(&html))
+    }
+}
+
+/// Writes through a temporary file so an interrupted fetch never leaves a
+/// truncated input in the cache.
+fn store(path: &Path, input: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, input)?;
+    fs::rename(partial, path)
+}
+
+/// The session token from `AOC_SESSION`, or else the first line of
+/// `$XDG_CONFIG_HOME/aoc/session` (`~/.config/aoc/session` by default).
+pub fn session_token() -> Option<String> {
+    if let Ok(token) = env::var(SESSION_VAR) {
+        return Some(token.trim().to_string()).filter(|t| !t.is_empty());
+    }
+
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    let token = fs::read_to_string(config.join("aoc").join("session")).ok()?;
+    token
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(PathBuf, io::Error),
+    NoSession(PathBuf),
+    Http(String, HttpError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(path, e) => write!(f, "error loading {}: {}", path.display(), e),
+            InputError::NoSession(path) => write!(
+                f,
+                "{} is missing and there is no session token to fetch it with \
+                 (set {} or write it to ~/.config/aoc/session)",
+                path.display(),
+                SESSION_VAR
+            ),
+            InputError::Http(url, e) => write!(f, "error fetching {}: {}", url, e),
+        }
+    }
+}
+
+impl error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves `body` for every request carrying the `good` session cookie
+    /// and 400s the rest, counting requests.
+    fn stand_in_server(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2024", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut authorised = false;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    authorised |= line.eq_ignore_ascii_case("cookie: session=good\r\n");
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let (status, body) = match authorised {
+                    true => ("200 OK", body),
+                    false => ("400 Bad Request", "Please log in"),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        (url, requests)
+    }
+
+    #[test]
+    fn fetches_once_then_reads_the_cache() {
+        let (url, requests) = stand_in_server("1 2\n3 4\n");
+        let root = env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let inputs = InputProvider::new(&root)
+            .with_base_url(url.clone())
+            .with_session(Some("good".to_string()));
+        assert_eq!(inputs.get(1).unwrap(), "1 2\n3 4\n");
+        assert_eq!(inputs.get(1).unwrap(), "1 2\n3 4\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(inputs.path(1).exists());
+
+        let rejected = InputProvider::new(&root)
+            .with_base_url(url)
+            .with_session(Some("bad".to_string()));
+        assert!(matches!(rejected.get(2), Err(InputError::Http(..))));
+        assert!(!rejected.path(2).exists());
+
+        let offline = InputProvider::new(&root).with_session(None);
+        assert!(matches!(offline.get(3), Err(InputError::NoSession(_))));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -3,6 +3,30 @@ use std::{
     io::{self, Read},
 };
 
+pub mod animate;
+pub mod answers;
+pub mod differential;
+pub mod error;
+pub mod examples;
+pub mod geom;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod log;
+pub mod parallel;
+pub mod parse;
+pub mod property;
+pub mod random;
+pub mod render;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod submit;
+
+pub use error::AocError;
+pub use grid::Grid;
+pub use solution::{Answer, Solution};
+
 pub fn load_input(input: &str) -> io::Result<String> {
     let mut file = fs::File::open(input)?;
     let mut contents = String::new();
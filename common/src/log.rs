@@ -0,0 +1,36 @@
+//! Debug output for whoever is watching a run. It goes to stderr and only
+//! appears at the verbosity asked for (`-v`, `-vv`), so stdout is left to
+//! answers and tools reading them.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+/// Whether messages at `level` (1 for [`debug!`], 2 for [`trace!`]) show.
+pub fn enabled(level: u8) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level
+}
+
+/// A message shown with `-v`: a summary or an unusual event.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled(1) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// A message shown with `-vv`: step-by-step detail, possibly a lot of it.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled(2) {
+            eprintln!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,119 @@
+//! Running independent work across a pool of threads: whole days from the
+//! runner, and the loops inside a day that try every cell, design or machine
+//! on its own. Results always come back in the order of the input, so output
+//! is the same whatever the number of threads.
+//!
+//! The pool is rayon's, which lets a day running on it split its own loops
+//! over the same threads rather than starting more.
+
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+};
+
+use rayon::prelude::*;
+
+use crate::{error::panic_message, AocError};
+
+/// Sizes the pool to `jobs` threads, or one per CPU for 0. Only works
+/// before the first parallel call, which otherwise sizes it by default.
+pub fn set_jobs(jobs: usize) -> Result<(), AocError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build_global()
+        .map_err(|e| AocError::new(format!("can't start {jobs} worker threads: {e}")))
+}
+
+/// `f` of every item, worked out in parallel, in the order of `items`.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    items.par_iter().map(f).collect()
+}
+
+/// Works out `f` of every item in parallel and hands each result to `done`
+/// on the calling thread, in the order of `items`, as soon as it and every
+/// result before it are ready. So a slow item holds back the ones after it,
+/// but never the ones before. An item whose `f` panics gets the panic's
+/// message instead, and the others carry on.
+pub fn each<T: Sync, R: Send>(
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync + Send,
+    mut done: impl FnMut(&T, Result<R, String>),
+) {
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        // The pool does the work while this thread waits for results, so
+        // `done` needn't be `Send` and never blocks a pool thread
+        scope.spawn(|| {
+            items
+                .par_iter()
+                .enumerate()
+                .for_each_with(sender, |sender, (i, item)| {
+                    // Only fails if the receiver panicked, which the scope
+                    // reports anyway
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                        .map_err(|payload| panic_message(&*payload));
+                    let _ = sender.send((i, result));
+                });
+        });
+
+        let mut ready = BTreeMap::new();
+        let mut next = 0;
+        for (i, result) in receiver {
+            ready.insert(i, result);
+            while let Some(result) = ready.remove(&next) {
+                done(&items[next], result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn results_come_back_in_order() {
+        let items: Vec<u64> = (0..50).collect();
+
+        // Earlier items take longer, so they finish last
+        let slow_first = |&n: &u64| {
+            thread::sleep(Duration::from_micros(50 - n));
+            n * n
+        };
+        assert_eq!(
+            map(&items, slow_first),
+            items.iter().map(|n| n * n).collect::<Vec<_>>()
+        );
+
+        let mut seen = vec![];
+        each(&items, slow_first, |&n, square| {
+            seen.push((n, square.unwrap()))
+        });
+        assert_eq!(seen, items.iter().map(|&n| (n, n * n)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn a_panicking_item_fails_alone() {
+        let items: Vec<u64> = (0..10).collect();
+        let mut seen = vec![];
+        each(
+            &items,
+            |&n| match n {
+                3 => panic!("no threes"),
+                n => n * 2,
+            },
+            |&n, doubled| seen.push((n, doubled)),
+        );
+
+        assert_eq!(seen.len(), items.len());
+        assert_eq!(seen[3], (3, Err("panicked: no threes".to_string())));
+        assert!(seen
+            .iter()
+            .all(|&(n, ref doubled)| n == 3 || *doubled == Ok(n * 2)));
+    }
+}
@@ -0,0 +1,138 @@
+//! Parsers for the input shapes that keep coming back. Everything works on
+//! [`Line`]s, which remember where they came from so errors can point there.
+
+use std::str::FromStr;
+
+use crate::{error::parse_token, AocError, Grid};
+
+/// A line of input and its 0-based index in the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Groups of lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut section = vec![];
+    for line in lines(input) {
+        if !line.text.trim().is_empty() {
+            section.push(line);
+        } else if !section.is_empty() {
+            sections.push(std::mem::take(&mut section));
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+/// A rectangular grid of single digits.
+pub fn digit_grid(input: &str) -> Result<Grid<u8>, AocError> {
+    Grid::parse_with(input, |c| c.to_digit(10).map(|d| d as u8))
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, message: impl Into<String>) -> AocError {
+        AocError::invalid_line(self.index, self.text, message)
+    }
+
+    /// An error about `token`, which must be a slice of this line.
+    pub fn token_error(&self, token: &str, message: impl Into<String>) -> AocError {
+        AocError::invalid_token(self.index, self.text, token, message)
+    }
+
+    /// Parses `token`, a slice of this line.
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, AocError> {
+        parse_token(self.index, self.text, token)
+    }
+
+    /// Every whitespace-separated field, each parsed as a `T`.
+    pub fn fields<T: FromStr>(&self) -> Result<Vec<T>, AocError> {
+        self.text
+            .split_whitespace()
+            .map(|field| self.parse(field))
+            .collect()
+    }
+
+    /// Every integer in the line, ignoring whatever is around them. A `-`
+    /// directly in front of the digits makes the number negative.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>, AocError> {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.parse(&self.text[start..i])?);
+        }
+        Ok(ints)
+    }
+
+    /// Splits `key: value`, returning both halves as slices of the line.
+    pub fn key_value(&self) -> Result<(&'a str, &'a str), AocError> {
+        self.text
+            .split_once(": ")
+            .ok_or_else(|| self.error("expected `<key>: <value>`"))
+    }
+
+    /// Parses the comma-separated items of `list`, a slice of this line
+    /// (often the whole line, or a value from `key_value`). Items are
+    /// trimmed and may not be empty.
+    pub fn comma_list<T: FromStr>(&self, list: &'a str) -> Result<Vec<T>, AocError> {
+        list.split(',')
+            .map(|item| match item.trim() {
+                "" => Err(self.token_error(item, "empty list item")),
+                item => self.parse(item),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_signed_integers() {
+        let line = Line {
+            index: 0,
+            text: "p=0,4 v=3,-3 x-1",
+        };
+
+        assert_eq!(line.ints::<i32>().unwrap(), [0, 4, 3, -3, -1]);
+    }
+
+    #[test]
+    fn keeps_line_numbers_across_sections() {
+        let input = "Register A: 729\nRegister B: 0\n\n\nProgram: 0,1,,4\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 2);
+
+        let program = sections[1][0];
+        let (key, value) = program.key_value().unwrap();
+        assert_eq!(key, "Program");
+
+        let err = program.comma_list::<u8>(value).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(5), Some(14)));
+        assert_eq!(err.message, "empty list item");
+    }
+}
@@ -0,0 +1,60 @@
+//! Property tests: check that something holds for many generated inputs,
+//! small ones first, and say which input broke it.
+
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::random::Rng;
+
+/// How many inputs [`check`] tries unless a test asks for more or fewer.
+pub const CASES: usize = 256;
+
+/// Runs `holds` on [`CASES`] inputs from `generate`, seeded 0, 1, 2 and so
+/// on, with sizes growing from 1 to `max_size`. `holds` asserts whatever it
+/// likes; the first failure is re-raised with the seed, size and input.
+pub fn check<I: fmt::Debug>(
+    max_size: usize,
+    generate: impl Fn(&mut Rng, usize) -> I,
+    holds: impl Fn(&I),
+) {
+    check_n(CASES, max_size, generate, holds)
+}
+
+/// [`check`] with a given number of cases.
+pub fn check_n<I: fmt::Debug>(
+    cases: usize,
+    max_size: usize,
+    generate: impl Fn(&mut Rng, usize) -> I,
+    holds: impl Fn(&I),
+) {
+    for seed in 0..cases as u64 {
+        let size = 1 + seed as usize * max_size.max(1) / cases.max(1);
+        let input = generate(&mut Rng::new(seed), size);
+        if panic::catch_unwind(AssertUnwindSafe(|| holds(&input))).is_err() {
+            panic!("property failed on seed {seed}, size {size}, input:\n{input:#?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_failing_input() {
+        check(
+            10,
+            |rng, size| rng.range(0..size as i64),
+            |&n| assert!(n >= 0),
+        );
+
+        let failure = panic::catch_unwind(|| {
+            check(10, |_, size| size, |&size| assert!(size < 5));
+        })
+        .unwrap_err();
+        let message = failure.downcast_ref::<String>().unwrap();
+        assert!(message.contains("size 5, input:\n5"), "{message}");
+    }
+}
@@ -0,0 +1,143 @@
+//! Seeded randomness for generating puzzle inputs. The same seed always
+//! gives the same input, so a slow or failing case can be passed around as
+//! a seed and size.
+
+use std::ops::Range;
+
+use crate::{
+    geom::{Direction4, Point},
+    search::bfs,
+    Grid,
+};
+
+/// SplitMix64: small, fast and good enough for test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        let span = range.end.abs_diff(range.start);
+        range.start.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// An index below `n`, which must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A `size` by `size` maze of `#` and `.` with `S` in the bottom-left and
+/// `E` in the top-right corner, always connected. `size` is rounded down to
+/// an odd number, and is at least 5. With `loops` above zero, that fraction of
+/// the remaining inner walls is knocked through so there is more than one
+/// way round.
+pub fn maze(rng: &mut Rng, size: usize, loops: f64) -> Grid<char> {
+    let size = (size.max(5) - 1) / 2 * 2 + 1;
+    let mut grid = Grid::new(size, size, '#');
+
+    // Carve a spanning tree over the odd cells with a depth-first walk
+    let start = Point::new(size - 2, 1);
+    grid[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited: Vec<(Direction4, Point)> = Direction4::ALL
+            .into_iter()
+            .filter_map(|dir| Some((dir, grid.step(cell, dir.vector() * 2)?)))
+            .filter(|&(_, next)| next.row % 2 == 1 && next.col % 2 == 1 && grid[next] == '#')
+            .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (dir, next) = *rng.pick(&unvisited);
+        grid[cell.offset(dir).unwrap()] = '.';
+        grid[next] = '.';
+        stack.push(next);
+    }
+
+    if loops > 0.0 {
+        let inner: Vec<Point> = grid
+            .positions()
+            .filter(|pos| (1..size - 1).contains(&pos.row) && (1..size - 1).contains(&pos.col))
+            .filter(|&pos| grid[pos] == '#' && (pos.row % 2 == 1) != (pos.col % 2 == 1))
+            .collect();
+        for pos in inner {
+            if rng.chance(loops) {
+                grid[pos] = '.';
+            }
+        }
+    }
+
+    grid[start] = 'S';
+    grid[Point::new(1, size - 2)] = 'E';
+    grid
+}
+
+/// Keeps only the cells of `maze` on its shortest route from `S` to `E`,
+/// walling off everything else. On a maze without loops that leaves a
+/// single track.
+pub fn track(maze: &Grid<char>) -> Grid<char> {
+    let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+    let search = bfs([start], |&pos| {
+        maze.neighbors4(pos).filter(|&next| maze[next] != '#')
+    });
+
+    let mut track = maze.map(|_| '#');
+    for pos in search.path_to(&end).unwrap() {
+        track[pos] = '.';
+    }
+    track[start] = 'S';
+    track[end] = 'E';
+    track
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_maze() {
+        let a = maze(&mut Rng::new(7), 20, 0.1);
+        let b = maze(&mut Rng::new(7), 20, 0.1);
+        assert_eq!(a.to_string(), b.to_string());
+        assert_eq!((a.width(), a.height()), (19, 19));
+
+        let track = track(&a);
+        let open = track.iter().filter(|&(_, &c)| c != '#').count();
+        let start = track.find(&'S').unwrap();
+        let steps = bfs([start], |&pos| {
+            track.neighbors4(pos).filter(|&next| track[next] != '#')
+        });
+        assert_eq!(steps.cost(&track.find(&'E').unwrap()), Some(open - 1));
+    }
+}
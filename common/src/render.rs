@@ -0,0 +1,264 @@
+//! Pictures of grid puzzles: a [`Canvas`] of coloured glyphs, built from a
+//! day's grid and overlays, written out as ANSI text, PPM, PNG or SVG.
+
+use std::fmt::Write;
+
+use crate::{geom::Point, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0x10, 0x10, 0x18);
+    pub const GREY: Rgb = Rgb(0x60, 0x60, 0x70);
+    pub const WHITE: Rgb = Rgb(0xe0, 0xe0, 0xe0);
+    pub const RED: Rgb = Rgb(0xd0, 0x30, 0x30);
+    pub const GREEN: Rgb = Rgb(0x30, 0xb0, 0x50);
+    pub const YELLOW: Rgb = Rgb(0xe0, 0xc0, 0x30);
+    pub const BLUE: Rgb = Rgb(0x40, 0x70, 0xd0);
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A distinct colour for the `n`th region, spreading hues by the golden
+/// angle so neighbouring ids don't look alike.
+pub fn palette(n: usize) -> Rgb {
+    let hue = (n as f64 * 137.507_764) % 360.0;
+    let (s, v) = (0.55, 0.85);
+
+    let c = v * s;
+    let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let channel = |value: f64| ((value + v - c) * 255.0).round() as u8;
+    Rgb(channel(r), channel(g), channel(b))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Rgb,
+    pub bg: Rgb,
+}
+
+impl Cell {
+    pub fn new(glyph: char, fg: Rgb, bg: Rgb) -> Self {
+        Cell { glyph, fg, bg }
+    }
+}
+
+/// The usual look of a puzzle map: walls as grey blocks, everything else
+/// white on black. Images only show backgrounds, so walls need one.
+pub fn map_cell(&tile: &char) -> Cell {
+    match tile {
+        '#' => Cell::new('#', Rgb::BLACK, Rgb::GREY),
+        tile => Cell::new(tile, Rgb::WHITE, Rgb::BLACK),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    pub fn from_grid<T>(grid: &Grid<T>, style: impl FnMut(&T) -> Cell) -> Self {
+        Canvas {
+            cells: grid.map(style),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn cell(&self, pos: Point) -> Option<&Cell> {
+        self.cells.get(pos)
+    }
+
+    pub fn cell_mut(&mut self, pos: Point) -> Option<&mut Cell> {
+        self.cells.get_mut(pos)
+    }
+
+    /// Paints the background of `cells`, skipping any off the canvas.
+    pub fn highlight(&mut self, cells: impl IntoIterator<Item = Point>, colour: Rgb) {
+        for pos in cells {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.bg = colour;
+            }
+        }
+    }
+
+    /// Draws `path` in order: each step gets an arrow towards the next one
+    /// and the background `colour`.
+    pub fn path(&mut self, path: &[Point], colour: Rgb) {
+        for (i, &pos) in path.iter().enumerate() {
+            let arrow = path.get(i + 1).map(|&next| {
+                let step = next - pos;
+                match (step.dr.signum(), step.dc.signum()) {
+                    (-1, _) => '^',
+                    (1, _) => 'v',
+                    (_, -1) => '<',
+                    _ => '>',
+                }
+            });
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.bg = colour;
+                cell.fg = Rgb::BLACK;
+                if let Some(arrow) = arrow {
+                    cell.glyph = arrow;
+                }
+            }
+        }
+    }
+
+    /// Colours every cell by its region id, using [`palette`].
+    pub fn regions(&mut self, region_of: &Grid<usize>) {
+        for (pos, &region) in region_of.iter() {
+            if let Some(cell) = self.cells.get_mut(pos) {
+                cell.bg = palette(region);
+                cell.fg = Rgb::BLACK;
+            }
+        }
+    }
+
+    /// The glyphs with 24-bit colour escapes, a line per row.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            for cell in row {
+                let (Rgb(fr, fg, fb), Rgb(br, bg, bb)) = (cell.fg, cell.bg);
+                let _ = write!(
+                    out,
+                    "\x1b[38;2;{fr};{fg};{fb}m\x1b[48;2;{br};{bg};{bb}m{}",
+                    cell.glyph
+                );
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// Row-major RGB pixels, `scale` pixels square per cell.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    for _ in 0..scale {
+                        pixels.extend([cell.bg.0, cell.bg.1, cell.bg.2]);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// A binary PPM (P6) with each cell drawn as a `scale`-pixel square.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let mut out = format!(
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )
+        .into_bytes();
+        out.extend(self.pixels(scale));
+        out
+    }
+
+    /// A PNG with each cell drawn as a `scale`-pixel square.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut out,
+            (self.width() * scale) as u32,
+            (self.height() * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        // Writing to a Vec can't fail
+        let mut writer = encoder.write_header().expect("PNG header");
+        writer
+            .write_image_data(&self.pixels(scale))
+            .expect("PNG data");
+        writer.finish().expect("PNG end");
+        out
+    }
+
+    /// An SVG with a `scale`-unit square and the glyph for each cell.
+    pub fn to_svg(&self, scale: usize) -> String {
+        let scale = scale.max(1);
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+             viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"{font}\" \
+             text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+            w = self.width() * scale,
+            h = self.height() * scale,
+            font = scale * 4 / 5,
+        );
+        for (pos, cell) in self.cells.iter() {
+            let (x, y) = (pos.col * scale, pos.row * scale);
+            let _ = writeln!(
+                out,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{scale}\" height=\"{scale}\" fill=\"{}\"/>",
+                cell.bg.hex()
+            );
+            let glyph = match cell.glyph {
+                '<' => "&lt;".to_string(),
+                '>' => "&gt;".to_string(),
+                '&' => "&amp;".to_string(),
+                glyph => glyph.to_string(),
+            };
+            if glyph != "." {
+                let _ = writeln!(
+                    out,
+                    "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>",
+                    x + scale / 2,
+                    y + scale / 2,
+                    cell.fg.hex(),
+                    glyph
+                );
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_paths_and_writes_images() {
+        let grid = Grid::parse("S.#\n..E\n").unwrap();
+        let mut canvas = Canvas::from_grid(&grid, map_cell);
+        let path = [(0, 0), (1, 0), (1, 1), (1, 2)].map(|(r, c)| Point::new(r, c));
+        canvas.path(&path, Rgb::YELLOW);
+
+        let glyphs: String = canvas.cells.iter().map(|(_, cell)| cell.glyph).collect();
+        assert_eq!(glyphs, "v.#>>E");
+        assert_eq!(canvas.cell(Point::new(1, 2)).unwrap().bg, Rgb::YELLOW);
+
+        let ppm = canvas.to_ppm(2);
+        assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(ppm.len(), b"P6\n6 4\n255\n".len() + 6 * 4 * 3);
+        assert!(canvas.to_png(2).starts_with(b"\x89PNG"));
+        assert_eq!(canvas.to_svg(10).matches("<rect").count(), 6);
+    }
+}
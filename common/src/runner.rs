@@ -0,0 +1,155 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{animate::Recorder, random::Rng, render::Canvas, Answer, AocError, Solution};
+
+/// Parses the input once and answers the requested parts.
+pub type Runner = fn(&str, &[u8]) -> Result<Solved, AocError>;
+
+/// Times parsing and both parts over the given number of iterations.
+pub type Bencher = fn(&str, usize) -> Result<Timings, AocError>;
+
+/// Parses the input and draws it, if the day knows how.
+pub type Renderer = fn(&str) -> Result<Option<Canvas>, AocError>;
+
+/// Parses the input and replays it into the recorder.
+pub type Animator = fn(&str, &mut Recorder) -> Result<(), AocError>;
+
+/// Makes a random input of roughly the given size, if the day knows how.
+pub type Generator = fn(&mut Rng, Option<usize>) -> Option<String>;
+
+/// A day's `Solution` with its types erased, so days can share a registry.
+pub struct Day {
+    pub day: u8,
+    pub run: Runner,
+    pub bench: Bencher,
+    pub render: Renderer,
+    pub animate: Animator,
+    pub generate: Generator,
+}
+
+pub const fn day<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        run: run::<S>,
+        bench: bench::<S>,
+        render: render::<S>,
+        animate: animate::<S>,
+        generate: S::generate,
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input).map_err(|e| e.with_day(S::DAY))?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed),
+                _ => S::part2(&parsed),
+            };
+            PartAnswer {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+    Ok(Solved { parse, parts })
+}
+
+/// The answers to the parts asked for, with how long parsing and each part
+/// took on a single run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<PartAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+fn bench<S: Solution>(input: &str, iterations: usize) -> Result<Timings, AocError> {
+    let parsed = S::parse(input).map_err(|e| e.with_day(S::DAY))?;
+    Ok(Timings {
+        parse: Stats::time(iterations, || S::parse(input)),
+        part1: Stats::time(iterations, || S::part1(&parsed)),
+        part2: Stats::time(iterations, || S::part2(&parsed)),
+    })
+}
+
+fn render<S: Solution>(input: &str) -> Result<Option<Canvas>, AocError> {
+    let parsed = S::parse(input).map_err(|e| e.with_day(S::DAY))?;
+    Ok(S::render(&parsed))
+}
+
+fn animate<S: Solution>(input: &str, recorder: &mut Recorder) -> Result<(), AocError> {
+    let parsed = S::parse(input).map_err(|e| e.with_day(S::DAY))?;
+    S::animate(&parsed, recorder);
+    Ok(())
+}
+
+/// Wall-clock times for each phase of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Runs `f` `iterations` times (at least once), timing each call but not
+    /// dropping its result.
+    pub fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+        let samples = (0..iterations.max(1)).map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            black_box(result);
+            elapsed
+        });
+        Stats::from_samples(samples.collect())
+    }
+
+    /// Summarises a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_samples() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,303 @@
+//! Shortest-path searches over implicit graphs. Nodes are any hashable value
+//! and edges come from a successor function, so callers never build a graph.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Best costs from the start nodes and, for each node reached, every
+/// predecessor it can be reached from at that cost.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N, C> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy,
+{
+    fn new() -> Self {
+        Search {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// The nodes `node` is reached from on a shortest path; empty for starts.
+    pub fn parents(&self, node: &N) -> &[N] {
+        self.parents.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a start node to `node`, both ends included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        // Parents always lead back towards a start; a search that records
+        // a cycle is broken, and a truncated path would hide it
+        let mut seen = HashSet::from([node.clone()]);
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents(path.last()?).first() {
+            assert!(
+                seen.insert(parent.clone()),
+                "the parents of a search lead round in a cycle"
+            );
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on some shortest path from a start node to one of `targets`.
+    pub fn nodes_on_paths_to(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = targets.into_iter().filter(|t| self.contains(t)).collect();
+
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.parents(&node).iter().cloned());
+            }
+        }
+        nodes
+    }
+}
+
+/// Breadth-first search from `starts`, where every edge costs one step.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.contains(&start) {
+            search.costs.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let cost = search.costs[&node] + 1;
+        for next in successors(&node) {
+            match search.costs.get(&next) {
+                None => {
+                    search.costs.insert(next.clone(), cost);
+                    search.parents.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+                Some(&best) if best == cost => {
+                    search.parents.entry(next).or_default().push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm from `starts`; `successors` yields each neighbour
+/// with the non-negative cost of the edge to it. With zero-cost edges a
+/// node only counts as a parent of nodes settled after it, so following
+/// parents never goes round in a circle.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut settled = HashSet::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.contains(&start) {
+            search.costs.insert(start.clone(), C::default());
+            heap.push(Queued::new(C::default(), C::default(), start));
+        }
+    }
+
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if search.costs[&node] < cost || !settled.insert(node.clone()) {
+            continue;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            debug_assert!(next_cost >= cost, "dijkstra needs non-negative costs");
+            match search.costs.get(&next) {
+                Some(&best) if best < next_cost => {}
+                Some(_) if settled.contains(&next) => {}
+                Some(&best) if best == next_cost => {
+                    search.parents.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    search.costs.insert(next.clone(), next_cost);
+                    search.parents.insert(next.clone(), vec![node.clone()]);
+                    heap.push(Queued::new(next_cost, next_cost, next));
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// A* from `start` to the first node satisfying `is_goal`, returning the
+/// path (both ends included) and its cost. `heuristic` must never
+/// overestimate the remaining cost, or the path may not be the shortest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::new();
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    costs.insert(start.clone(), C::default());
+    heap.push(Queued::new(heuristic(&start), C::default(), start));
+
+    while let Some(Queued { cost, node, .. }) = heap.pop() {
+        if costs[&node] < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(parent) = parents.get(path.last()?) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), node.clone());
+                heap.push(Queued::new(next_cost + heuristic(&next), next_cost, next));
+            }
+        }
+    }
+
+    None
+}
+
+/// A heap entry ordered so the lowest `priority` pops first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C> Queued<N, C> {
+    fn new(priority: C, cost: C, node: N) -> Self {
+        Queued {
+            priority,
+            cost,
+            node,
+        }
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geom::Point, Grid};
+
+    #[test]
+    fn searches_agree_on_a_maze() {
+        let maze = Grid::parse("S..#\n.#..\n...E\n").unwrap();
+        let (start, end) = (maze.find(&'S').unwrap(), maze.find(&'E').unwrap());
+        let open = |pos: &Point| {
+            maze.neighbors4(*pos)
+                .filter(|&next| maze[next] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let by_bfs = bfs([start], open);
+        let by_dijkstra = dijkstra([start], |pos| open(pos).into_iter().map(|next| (next, 1)));
+        let (path, cost) = astar(
+            start,
+            |pos| open(pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.manhattan(end),
+            |pos| *pos == end,
+        )
+        .unwrap();
+
+        assert_eq!(by_bfs.cost(&end), Some(5));
+        assert_eq!(by_dijkstra.cost(&end), Some(5));
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 6);
+        assert_eq!(by_bfs.path_to(&end).unwrap().first(), Some(&start));
+        // Both routes around the wall are shortest
+        assert_eq!(by_bfs.nodes_on_paths_to([end]).len(), 10);
+    }
+
+    #[test]
+    fn zero_cost_cycles_leave_paths_finite() {
+        // 0 and 1 lead to each other for free; 2 is a step on from either
+        let search = dijkstra([0], |&n: &u32| match n {
+            0 => vec![(1, 0), (2, 1)],
+            1 => vec![(0, 0), (2, 1)],
+            _ => vec![],
+        });
+
+        assert_eq!(search.cost(&1), Some(0));
+        assert_eq!(search.parents(&0), &[] as &[u32]);
+        assert_eq!(search.path_to(&1), Some(vec![0, 1]));
+        assert_eq!(search.parents(&2), &[0, 1]);
+        assert_eq!(search.nodes_on_paths_to([2]).len(), 3);
+    }
+}
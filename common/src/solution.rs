@@ -0,0 +1,97 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::Serialize;
+
+use crate::{animate::Recorder, random::Rng, render::Canvas, AocError};
+
+/// The answer to one part of a puzzle. Most days produce a number, a few
+/// (like day17's program output) produce text. Serialized as a bare number
+/// or string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// Puzzle constants an example sets for itself by name, such as a smaller
+/// grid; see [`Solution::configure`].
+pub type Settings = BTreeMap<String, usize>;
+
+/// A day's puzzle: parse the input once, then answer both parts from it.
+///
+/// `parse` is the only place that sees raw text, so it is where malformed
+/// input is reported; the parts can rely on what it returns.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Swaps the real puzzle's constants in `input` for an example's
+    /// `settings` from `examples/answers.toml`, for days whose statement
+    /// shrinks them. Days without any reject every setting.
+    fn configure(_input: &mut Self::Input, settings: &Settings) -> Result<(), AocError> {
+        match settings.keys().next() {
+            Some(name) => Err(AocError::new(format!("unknown setting `{name}`"))),
+            None => Ok(()),
+        }
+    }
+
+    /// A picture of the puzzle for `--render`, for days on a grid.
+    fn render(_input: &Self::Input) -> Option<Canvas> {
+        None
+    }
+
+    /// Replays a simulation frame by frame into `recorder`, for days that
+    /// have one. Leaving it empty means there is nothing to animate.
+    fn animate(_input: &Self::Input, _recorder: &mut Recorder) {}
+
+    /// A random input that `parse` accepts, for days that can make one.
+    /// What `size` counts (lines, machines, the side of a maze) is up to
+    /// the day, which also picks a size like the real input's when none is
+    /// given.
+    fn generate(_rng: &mut Rng, _size: Option<usize>) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,520 @@
+use std::{
+    collections::BTreeMap,
+    error, fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::{Client, Http, HttpError},
+    input::{session_token, BASE_URL, SESSION_VAR},
+    load_input,
+};
+
+/// Posts answers to the site, keeping every attempt in a history file so
+/// that answers already known to be wrong (or outside the bounds earlier
+/// "too high"/"too low" replies set) are never sent again.
+pub struct Submitter {
+    history: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    http: Box<dyn Http>,
+}
+
+impl Submitter {
+    pub fn new(history: impl Into<PathBuf>) -> Self {
+        Submitter {
+            history: history.into(),
+            base_url: BASE_URL.to_string(),
+            session: session_token(),
+            http: Box::new(Client::default()),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        self
+    }
+
+    pub fn with_http(mut self, http: impl Http + 'static) -> Self {
+        self.http = Box::new(http);
+        self
+    }
+
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Response, SubmitError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.submit_at(day, part, answer, now)
+    }
+
+    fn submit_at(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<Response, SubmitError> {
+        let mut history = History::load(&self.history)?;
+        history
+            .check(day, part, answer, now)
+            .map_err(SubmitError::Refused)?;
+
+        let session = self.session.as_deref().ok_or(SubmitError::NoSession)?;
+        let url = format!("{}/day/{}/answer", self.base_url, day);
+        let level = part.to_string();
+        let body = self
+            .http
+            .post_form(&url, session, &[("level", &level), ("answer", answer)])
+            .map_err(|e| SubmitError::Http(url, e))?;
+
+        let response = Response::parse(&body);
+        history.record(
+            day,
+            part,
+            Attempt {
+                answer: answer.to_string(),
+                outcome: response.outcome,
+                at: now,
+                wait: response.wait.map(|wait| wait.as_secs()),
+            },
+        );
+        history.save(&self.history)?;
+        Ok(response)
+    }
+}
+
+/// What the site made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Sent before the wait from an earlier attempt was over.
+    RateLimited,
+    /// The part is already solved, or the first part isn't yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long the site wants us to wait before answering again.
+    pub wait: Option<Duration>,
+    /// The reply with markup stripped.
+    pub message: String,
+}
+
+impl Response {
+    pub fn parse(html: &str) -> Response {
+        let message = article_text(html);
+        let lower = message.to_lowercase();
+
+        let outcome = if lower.contains("that's the right answer") {
+            Outcome::Correct
+        } else if lower.contains("answer too recently") {
+            Outcome::RateLimited
+        } else if lower.contains("not the right answer") {
+            if lower.contains("too high") {
+                Outcome::TooHigh
+            } else if lower.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if lower.contains("solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        };
+
+        Response {
+            outcome,
+            wait: parse_wait(&lower),
+            message,
+        }
+    }
+}
+
+/// The text of the reply's `<article>`, or of the whole page without one.
+fn article_text(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "you have 1m 5s left to wait" and "please wait one minute/5
+/// minutes before trying again".
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("you have ")?;
+        let mut seconds = 0;
+        for amount in left.split_whitespace() {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            seconds += value
+                * match unit {
+                    "h" => 3600,
+                    "m" => 60,
+                    "s" => 1,
+                    _ => return None,
+                };
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, rest) = message.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        "a" => 1,
+        count => count.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// Every attempt so far, stored as TOML:
+///
+/// ```toml
+/// [[day19.part2]]
+/// answer = "565600047715343"
+/// outcome = "correct"
+/// at = 1734567890
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+struct History {
+    days: BTreeMap<String, BTreeMap<String, Vec<Attempt>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Attempt {
+    answer: String,
+    outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    at: u64,
+    /// Seconds the site asked us to wait afterwards.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait: Option<u64>,
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self, SubmitError> {
+        match load_input(&path.to_string_lossy()) {
+            Ok(text) => toml::from_str(&text).map_err(|e| {
+                SubmitError::History(
+                    path.to_path_buf(),
+                    io::Error::new(io::ErrorKind::InvalidData, e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(SubmitError::History(path.to_path_buf(), e)),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let text = toml::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .map_err(|e| SubmitError::History(path.to_path_buf(), e))?;
+        fs::write(path, text).map_err(|e| SubmitError::History(path.to_path_buf(), e))
+    }
+
+    fn attempts(&self, day: u8, part: u8) -> &[Attempt] {
+        self.days
+            .get(&format!("day{day}"))
+            .and_then(|parts| parts.get(&format!("part{part}")))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    fn record(&mut self, day: u8, part: u8, attempt: Attempt) {
+        self.days
+            .entry(format!("day{day}"))
+            .or_default()
+            .entry(format!("part{part}"))
+            .or_default()
+            .push(attempt);
+    }
+
+    /// Whether `answer` is worth sending at `now`.
+    fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = self.attempts(day, part);
+
+        if let Some(correct) = attempts.iter().find(|a| a.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved(correct.answer.clone()));
+        }
+        if let Some(known) = attempts
+            .iter()
+            .find(|a| a.answer == answer && a.outcome.is_wrong())
+        {
+            return Err(Refusal::KnownWrong(known.outcome));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome| {
+                attempts
+                    .iter()
+                    .filter(move |a| a.outcome == outcome)
+                    .filter_map(|a| a.answer.parse::<i128>().ok())
+            };
+            if let Some(high) = bound(Outcome::TooHigh).min().filter(|&high| value >= high) {
+                return Err(Refusal::TooHigh(high));
+            }
+            if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| value <= low) {
+                return Err(Refusal::TooLow(low));
+            }
+        }
+
+        let until = attempts.iter().filter_map(|a| Some(a.at + a.wait?)).max();
+        match until {
+            Some(until) if now < until => Err(Refusal::Wait(until - now)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Outcome),
+    TooHigh(i128),
+    TooLow(i128),
+    Wait(u64),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "this answer was already rejected ({:?})", outcome)
+            }
+            Refusal::TooHigh(high) => write!(f, "{} was already too high", high),
+            Refusal::TooLow(low) => write!(f, "{} was already too low", low),
+            Refusal::Wait(seconds) => write!(f, "wait {}s before answering again", seconds),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    NoSession,
+    Http(String, HttpError),
+    History(PathBuf, io::Error),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting: {}", refusal),
+            SubmitError::NoSession => write!(
+                f,
+                "no session token (set {} or write it to ~/.config/aoc/session)",
+                SESSION_VAR
+            ),
+            SubmitError::Http(url, e) => write!(f, "error posting to {}: {}", url, e),
+            SubmitError::History(path, e) => {
+                write!(f, "error with history {}: {}", path.display(), e)
+            }
+        }
+    }
+}
+
+impl error::Error for SubmitError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        collections::VecDeque,
+        env,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        rc::Rc,
+        thread,
+    };
+
+    use super::*;
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high. If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again.</p></article></main>";
+    const CORRECT: &str = "<article><p>That's the right answer! You are <em>one gold star</em> \
+        closer to finding the Chief Historian.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again. You have 1m 5s left to wait.</p></article>";
+
+    /// Replies to posts in order, remembering what was sent.
+    #[derive(Clone, Default)]
+    struct FakeSite {
+        replies: Rc<RefCell<VecDeque<&'static str>>>,
+        posted: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl Http for FakeSite {
+        fn get(&self, _: &str, _: &str) -> Result<String, HttpError> {
+            Err(HttpError::Status(404, String::new()))
+        }
+
+        fn post_form(
+            &self,
+            url: &str,
+            _: &str,
+            form: &[(&str, &str)],
+        ) -> Result<String, HttpError> {
+            self.posted.borrow_mut().push(format!("{url} {form:?}"));
+            let reply = self.replies.borrow_mut().pop_front().unwrap_or_default();
+            Ok(reply.to_string())
+        }
+    }
+
+    #[test]
+    fn parses_replies() {
+        let high = Response::parse(TOO_HIGH);
+        assert_eq!(high.outcome, Outcome::TooHigh);
+        assert_eq!(high.wait, Some(Duration::from_secs(60)));
+        assert!(high.message.starts_with("That's not the right answer"));
+
+        assert_eq!(Response::parse(CORRECT).outcome, Outcome::Correct);
+
+        let soon = Response::parse(TOO_SOON);
+        assert_eq!(soon.outcome, Outcome::RateLimited);
+        assert_eq!(soon.wait, Some(Duration::from_secs(65)));
+    }
+
+    #[test]
+    fn refuses_answers_the_history_rules_out() {
+        let history = env::temp_dir().join(format!("aoc-history-test-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&history);
+
+        let site = FakeSite::default();
+        site.replies.borrow_mut().extend([TOO_HIGH, CORRECT]);
+        let submitter = Submitter::new(&history)
+            .with_base_url("http://localhost/2024")
+            .with_session(Some("token".to_string()))
+            .with_http(site.clone());
+
+        let sent = submitter.submit_at(19, 2, "100", 1000).unwrap();
+        assert_eq!(sent.outcome, Outcome::TooHigh);
+
+        let refused = |answer, now| match submitter.submit_at(19, 2, answer, now) {
+            Err(SubmitError::Refused(refusal)) => refusal,
+            other => panic!("expected a refusal, got {other:?}"),
+        };
+        assert_eq!(refused("100", 2000), Refusal::KnownWrong(Outcome::TooHigh));
+        assert_eq!(refused("150", 2000), Refusal::TooHigh(100));
+        assert_eq!(refused("50", 1010), Refusal::Wait(50));
+
+        let sent = submitter.submit_at(19, 2, "50", 1060).unwrap();
+        assert_eq!(sent.outcome, Outcome::Correct);
+        assert_eq!(
+            refused("60", 2000),
+            Refusal::AlreadySolved("50".to_string())
+        );
+
+        assert_eq!(
+            *site.posted.borrow(),
+            [
+                r#"http://localhost/2024/day/19/answer [("level", "2"), ("answer", "100")]"#,
+                r#"http://localhost/2024/day/19/answer [("level", "2"), ("answer", "50")]"#,
+            ]
+        );
+        fs::remove_file(&history).unwrap();
+    }
+
+    #[test]
+    fn posts_the_form_a_real_server_expects() {
+        let history = env::temp_dir().join(format!("aoc-history-http-{}.toml", std::process::id()));
+        let _ = fs::remove_file(&history);
+
+        // Answers one request with CORRECT and hands back what was sent
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                head.push(line.trim_end().to_string());
+            }
+            let length = head
+                .iter()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{CORRECT}",
+                CORRECT.len()
+            )
+            .unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let submitter = Submitter::new(&history)
+            .with_base_url(format!("http://127.0.0.1:{port}/2024/"))
+            .with_session(Some("token".to_string()));
+        let sent = submitter.submit_at(17, 1, "4,6,3", 1000).unwrap();
+        assert_eq!(sent.outcome, Outcome::Correct);
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "POST /2024/day/17/answer HTTP/1.1");
+        let header = |name: &str| {
+            head.iter()
+                .find_map(|line| {
+                    let (key, value) = line.split_once(": ")?;
+                    key.eq_ignore_ascii_case(name).then(|| value.to_string())
+                })
+                .unwrap_or_else(|| panic!("no {name} header in {head:?}"))
+        };
+        assert_eq!(header("cookie"), "session=token");
+        assert_eq!(header("content-type"), "application/x-www-form-urlencoded");
+        assert_eq!(header("user-agent"), crate::http::USER_AGENT);
+        assert_eq!(body, "level=1&answer=4%2C6%2C3");
+        fs::remove_file(&history).unwrap();
+    }
+}
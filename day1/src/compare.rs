@@ -0,0 +1,221 @@
+//! Comparing two columns of a location list by more than the puzzle's total
+//! distance, for reconciling lists of the same IDs from different sources.
+
+use std::fmt;
+
+use common::Answer;
+
+use super::{too_large_answer, total_distance};
+
+/// A way of scoring how far apart two columns are. The columns come in
+/// input order and are the same length.
+pub trait Metric {
+    /// What `aoc lists --metric` calls it.
+    fn name(&self) -> &'static str;
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Answer;
+}
+
+/// Every built-in metric, the puzzle's first.
+pub const METRICS: &[&dyn Metric] = &[&Distance, &Squared, &MaxDeviation, &RankCorrelation];
+
+pub fn metric(name: &str) -> Option<&'static dyn Metric> {
+    METRICS.iter().copied().find(|metric| metric.name() == name)
+}
+
+/// The puzzle's total distance: the sum of the gaps between the smallest of
+/// each column, the second smallest, and so on.
+pub struct Distance;
+
+/// Like [`Distance`], squaring each gap so a few far-off pairs stand out.
+pub struct Squared;
+
+/// The widest gap between pairs matched up as for [`Distance`].
+pub struct MaxDeviation;
+
+/// Spearman's rank correlation between the columns as they are lined up in
+/// the input, from 1 when both sources order the rows alike to -1 when one
+/// reverses the other. Tied values share their average rank.
+pub struct RankCorrelation;
+
+impl Metric for Distance {
+    fn name(&self) -> &'static str {
+        "distance"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Answer {
+        let (left, right) = (sorted(left), sorted(right));
+        too_large_answer(total_distance(&left, &right))
+    }
+}
+
+impl Metric for Squared {
+    fn name(&self) -> &'static str {
+        "squared"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Answer {
+        // Two columns of extreme IDs can square past even an i128
+        let pairs = pair_diffs(left, right);
+        let sum = pairs.iter().try_fold(0u128, |sum, pair| {
+            sum.checked_add(pair.diff.unsigned_abs().pow(2))
+        });
+        match sum.and_then(|sum| i128::try_from(sum).ok()) {
+            Some(sum) => sum.into(),
+            None => "too large to count".into(),
+        }
+    }
+}
+
+impl Metric for MaxDeviation {
+    fn name(&self) -> &'static str {
+        "max-deviation"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Answer {
+        let pairs = pair_diffs(left, right);
+        pairs
+            .iter()
+            .map(|pair| pair.diff.abs())
+            .max()
+            .unwrap_or(0)
+            .into()
+    }
+}
+
+impl Metric for RankCorrelation {
+    fn name(&self) -> &'static str {
+        "rank-correlation"
+    }
+
+    fn score(&self, left: &[i64], right: &[i64]) -> Answer {
+        let (left, right) = (ranks(left), ranks(right));
+        let mean = (left.len() as f64 + 1.0) / 2.0;
+
+        let (mut covariance, mut left_spread, mut right_spread) = (0.0, 0.0, 0.0);
+        for (l, r) in left.iter().zip(&right) {
+            covariance += (l - mean) * (r - mean);
+            left_spread += (l - mean).powi(2);
+            right_spread += (r - mean).powi(2);
+        }
+        if left_spread == 0.0 || right_spread == 0.0 {
+            return "undefined, a column is constant".into();
+        }
+        format!("{:.6}", covariance / (left_spread * right_spread).sqrt()).into()
+    }
+}
+
+fn sorted(column: &[i64]) -> Vec<i64> {
+    let mut column = column.to_vec();
+    column.sort_unstable();
+    column
+}
+
+/// The 1-based rank of each value in its column, ties sharing the average
+/// of the ranks they span.
+fn ranks(column: &[i64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..column.len()).collect();
+    order.sort_unstable_by_key(|&i| column[i]);
+
+    let mut ranks = vec![0.0; column.len()];
+    let mut below = 0;
+    for tied in order.chunk_by(|&a, &b| column[a] == column[b]) {
+        let rank = below as f64 + (tied.len() as f64 + 1.0) / 2.0;
+        for &i in tied {
+            ranks[i] = rank;
+        }
+        below += tied.len();
+    }
+    ranks
+}
+
+/// One pair of a [`pair_diffs`] report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairDiff {
+    /// 1 for the smallest of each column, 2 for the next, and so on.
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    /// `right - left`, which needs more than an `i64` when the IDs are far
+    /// apart.
+    pub diff: i128,
+}
+
+impl fmt::Display for PairDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>8}  {:>12}  {:>12}  {:>+12}",
+            self.rank, self.left, self.right, self.diff
+        )
+    }
+}
+
+/// The columns paired up as for [`Distance`], smallest with smallest, with
+/// the gap in each pair.
+pub fn pair_diffs(left: &[i64], right: &[i64]) -> Vec<PairDiff> {
+    paired(&sorted(left), &sorted(right)).collect()
+}
+
+/// Sorted columns paired up in order, the way [`pair_diffs`] and the
+/// puzzle's total distance both see them.
+pub(crate) fn paired<'a>(left: &'a [i64], right: &'a [i64]) -> impl Iterator<Item = PairDiff> + 'a {
+    left.iter()
+        .zip(right)
+        .enumerate()
+        .map(|(i, (&left, &right))| PairDiff {
+            rank: i + 1,
+            left,
+            right,
+            diff: right as i128 - left as i128,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: [i64; 6] = [3, 4, 2, 1, 3, 3];
+    const RIGHT: [i64; 6] = [4, 3, 5, 3, 9, 3];
+
+    fn score(name: &str, left: &[i64], right: &[i64]) -> String {
+        metric(name).unwrap().score(left, right).to_string()
+    }
+
+    #[test]
+    fn scores_the_example_lists() {
+        let diffs: Vec<i128> = pair_diffs(&LEFT, &RIGHT)
+            .iter()
+            .map(|pair| pair.diff)
+            .collect();
+        assert_eq!(diffs, [2, 1, 0, 1, 2, 5]);
+
+        assert_eq!(score("distance", &LEFT, &RIGHT), "11");
+        assert_eq!(score("squared", &LEFT, &RIGHT), "35");
+        assert_eq!(score("max-deviation", &LEFT, &RIGHT), "5");
+        // Ranks 4 6 2 1 4 4 against 4 2 5 2 6 2: -1.5 / sqrt(15.5 * 15.5)
+        assert_eq!(score("rank-correlation", &LEFT, &RIGHT), "-0.096774");
+    }
+
+    #[test]
+    fn ties_share_their_average_rank() {
+        assert_eq!(ranks(&[20, 10, 30, 20]), [2.5, 1.0, 4.0, 2.5]);
+        assert_eq!(
+            score("rank-correlation", &[1, 2, 2], &[3, 2, 2]),
+            "-1.000000"
+        );
+        assert_eq!(
+            score("rank-correlation", &[1, 2, 3], &[7, 7, 7]),
+            "undefined, a column is constant"
+        );
+    }
+
+    #[test]
+    fn extreme_ids_do_not_overflow() {
+        let (left, right) = ([i64::MIN], [i64::MAX]);
+        assert_eq!(pair_diffs(&left, &right)[0].diff, u64::MAX as i128);
+        assert_eq!(score("max-deviation", &left, &right), u64::MAX.to_string());
+        assert_eq!(score("distance", &left, &right), u64::MAX.to_string());
+        assert_eq!(score("squared", &left, &right), "too large to count");
+    }
+}
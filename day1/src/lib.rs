@@ -0,0 +1,173 @@
+use std::{collections::HashMap, fmt::Write, io::BufRead};
+
+use common::{parse::Line, random::Rng, Answer, AocError, Solution};
+
+pub mod compare;
+
+/// Reads the two columns of location IDs a line at a time, so the lists
+/// never sit in memory as text as well as numbers. They come back unsorted.
+pub fn read_lists(reader: impl BufRead) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    let mut columns = read(reader, Some(2))?.into_iter();
+    Ok((columns.next().unwrap(), columns.next().unwrap()))
+}
+
+/// Like [`read_lists`], for any number of columns, as many as the first
+/// line has. Each column comes back in input order.
+pub fn read_columns(reader: impl BufRead) -> Result<Vec<Vec<i64>>, AocError> {
+    read(reader, None)
+}
+
+/// Reads `columns` numbers a line, or as many as the first line has.
+fn read(mut reader: impl BufRead, mut columns: Option<usize>) -> Result<Vec<Vec<i64>>, AocError> {
+    let mut lists: Vec<Vec<i64>> = vec![Vec::new(); columns.unwrap_or(0)];
+
+    let mut buffer = String::new();
+    for index in 0.. {
+        buffer.clear();
+        let read = reader
+            .read_line(&mut buffer)
+            .map_err(|e| AocError::on_line(index, format!("error reading: {e}")))?;
+        if read == 0 {
+            break;
+        }
+
+        let line = Line {
+            index,
+            text: buffer.trim_end_matches(['\n', '\r']),
+        };
+        let found = line.text.split_whitespace().count();
+        let expected = *columns.get_or_insert(found);
+        if found != expected || found == 0 {
+            let expected = match expected {
+                0 => "at least 1 column".to_string(),
+                1 => "1 column".to_string(),
+                n => format!("{n} columns"),
+            };
+            return Err(line.error(format!("expected {expected}, found {found}")));
+        }
+        lists.resize_with(found, Vec::new);
+        for (list, field) in lists.iter_mut().zip(line.text.split_whitespace()) {
+            list.push(line.parse(field)?);
+        }
+    }
+
+    Ok(lists)
+}
+
+/// Both answers for the lists in `reader`: the total distance between
+/// them and their similarity score.
+pub fn answers(reader: impl BufRead) -> Result<(i128, i128), AocError> {
+    let (mut left, mut right) = read_lists(reader)?;
+    left.sort_unstable();
+    right.sort_unstable();
+    let too_large = |answer| AocError::new(format!("the {answer} doesn't fit in an i128"));
+    Ok((
+        total_distance(&left, &right).ok_or_else(|| too_large("total distance"))?,
+        similarity_score(&left, &right).ok_or_else(|| too_large("similarity score"))?,
+    ))
+}
+
+/// Pairs the lists up smallest to smallest, so both must be sorted. `None`
+/// if the total doesn't fit in an `i128`, which an `i64` ID list can only
+/// overflow if it is longer than any machine could hold.
+fn total_distance(left: &[i64], right: &[i64]) -> Option<i128> {
+    compare::paired(left, right).try_fold(0i128, |sum, pair| sum.checked_add(pair.diff.abs()))
+}
+
+/// `None` if the score doesn't fit in an `i128`.
+fn similarity_score(left: &[i64], right: &[i64]) -> Option<i128> {
+    let mut counts: HashMap<i64, i128> = HashMap::with_capacity(right.len());
+    for &r in right {
+        *counts.entry(r).or_default() += 1;
+    }
+    left.iter().try_fold(0i128, |sum, &l| {
+        sum.checked_add((l as i128).checked_mul(*counts.get(&l).unwrap_or(&0))?)
+    })
+}
+
+fn too_large_answer(answer: Option<i128>) -> Answer {
+    match answer {
+        Some(answer) => answer.into(),
+        None => "too large to count".into(),
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// Both lists, sorted.
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let (mut left, mut right) = read_lists(input.as_bytes())?;
+        left.sort_unstable();
+        right.sort_unstable();
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Answer {
+        too_large_answer(total_distance(left, right))
+    }
+
+    fn part2((left, right): &Self::Input) -> Answer {
+        too_large_answer(similarity_score(left, right))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let lines = size.unwrap_or(1000);
+        let left: Vec<i64> = (0..lines).map(|_| rng.range(10_000..100_000)).collect();
+
+        // Reuse some left numbers on the right so the similarity isn't zero
+        let mut input = String::new();
+        for &l in &left {
+            let r = match rng.chance(0.3) {
+                true => *rng.pick(&left),
+                false => rng.range(10_000..100_000),
+            };
+            let _ = writeln!(input, "{}   {}", l, r);
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(super::Day1);
+
+    #[test]
+    fn answers_straight_from_a_reader() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(answers(input.as_bytes()), Ok((11, 31)));
+
+        // The widest gap an i64 allows, and twice the largest ID
+        let extremes = format!("{}   {}\n{}   {}\n", i64::MIN, i64::MAX, i64::MAX, i64::MAX);
+        assert_eq!(
+            answers(extremes.as_bytes()),
+            Ok((u64::MAX as i128, 2 * i64::MAX as i128))
+        );
+
+        let errors = [
+            (
+                &b"3   4\n4   x3\n"[..],
+                "line 2, column 5: invalid number: `x3`",
+            ),
+            (
+                b"3   4\n\xff   3\n",
+                "line 2: error reading: stream did not contain valid UTF-8",
+            ),
+        ];
+        for (input, error) in errors {
+            assert_eq!(read_lists(input).unwrap_err().to_string(), error);
+        }
+
+        let error = answers("1   2\n3   4   5\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (Some(2), "expected 2 columns, found 3")
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use common::{geom::Point, parse, search::bfs, Answer, AocError, Grid, Solution};
+
+fn solve(map: &Grid<u8>) -> usize {
+    let mut total_score = 0;
+
+    // Find all trailheads (height 0)
+    for (start, &height) in map.iter() {
+        if height == 0 {
+            let reachable = bfs([start], |&pos| {
+                map.neighbors4(pos)
+                    .filter(move |&next| map[next] == map[pos] + 1)
+            });
+
+            total_score += reachable
+                .costs()
+                .keys()
+                .filter(|&&pos| map[pos] == 9)
+                .count();
+        }
+    }
+
+    total_score
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::digit_grid(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        solve(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        solve_part2(map).into()
+    }
+}
+
+fn solve_part2(map: &Grid<u8>) -> usize {
+    let mut total_rating = 0;
+
+    for (start, &height) in map.iter() {
+        if height == 0 {
+            let mut cache = HashMap::new();
+            let rating = count_paths(map, start, &mut cache);
+            total_rating += rating;
+        }
+    }
+
+    total_rating
+}
+
+fn count_paths(map: &Grid<u8>, pos: Point, cache: &mut HashMap<Point, usize>) -> usize {
+    if map[pos] == 9 {
+        return 1;
+    }
+
+    if let Some(&count) = cache.get(&pos) {
+        return count;
+    }
+
+    let current_height = map[pos];
+    let mut paths = 0;
+
+    // Check all four directions
+    for next in map.neighbors4(pos) {
+        if map[next] == current_height + 1 {
+            paths += count_paths(map, next, cache);
+        }
+    }
+
+    cache.insert(pos, paths);
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day10);
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{
+    geom::{Direction4, Point, Vector},
+    render::{map_cell, Canvas},
+    search::bfs,
+    Answer, AocError, Grid, Solution,
+};
+
+struct Region {
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        process_map(map, |region| region.area * region.perimeter).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        process_map(map, |region| region.area * region.sides).into()
+    }
+
+    fn render(map: &Self::Input) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(map, map_cell);
+        canvas.regions(&region_ids(map));
+        Some(canvas)
+    }
+}
+
+/// Numbers the regions in reading order of their first plot.
+fn region_ids(map: &Grid<char>) -> Grid<usize> {
+    let mut ids = Grid::new(map.width(), map.height(), None);
+    let mut regions = 0;
+
+    for pos in map.positions() {
+        if ids[pos].is_some() {
+            continue;
+        }
+
+        let region = bfs([pos], |&plot| {
+            map.neighbors4(plot)
+                .filter(move |&next| map[next] == map[plot])
+        });
+        for &plot in region.costs().keys() {
+            ids[plot] = Some(regions);
+        }
+        regions += 1;
+    }
+    ids.map(|id| id.unwrap_or_default())
+}
+
+fn process_map(map: &Grid<char>, price: impl Fn(&Region) -> usize) -> usize {
+    let mut visited = HashSet::new();
+    let mut total = 0;
+
+    for pos in map.positions() {
+        if visited.contains(&pos) {
+            continue;
+        }
+
+        let region = find_region(map, pos, &mut visited);
+        total += price(&region);
+    }
+    total
+}
+
+fn find_region(map: &Grid<char>, start: Point, visited: &mut HashSet<Point>) -> Region {
+    let mut queue = VecDeque::new();
+    let mut region = Region {
+        area: 0,
+        perimeter: 0,
+        sides: 0,
+    };
+
+    let plot_type = map[start];
+    queue.push_back(start);
+
+    let same_plot = |pos: Point, offset: Vector| {
+        map.step(pos, offset)
+            .is_some_and(|next| map[next] == plot_type)
+    };
+
+    while let Some(pos) = queue.pop_front() {
+        if !visited.insert(pos) {
+            continue;
+        }
+
+        region.area += 1;
+
+        for dir in Direction4::ALL {
+            match map.step(pos, dir) {
+                Some(next) if map[next] == plot_type => queue.push_back(next),
+                _ => region.perimeter += 1,
+            }
+
+            // A region has as many sides as it has corners; each direction and
+            // the one clockwise from it form a corner
+            let (a, b) = (dir.vector(), dir.turn_right().vector());
+            let side_a = same_plot(pos, a);
+            let side_b = same_plot(pos, b);
+            let diagonal = same_plot(pos, a + b);
+            if (!side_a && !side_b) || (side_a && side_b && !diagonal) {
+                region.sides += 1;
+            }
+        }
+    }
+
+    region
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day12);
+}
@@ -0,0 +1,326 @@
+use common::{
+    parallel,
+    parse::{self, Line},
+    random::Rng,
+    Answer, AocError, Solution,
+};
+
+const PRIZE_OFFSET: i128 = 10_000_000_000_000;
+const MAX_PRESSES: i128 = 100;
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: (i128, i128),
+    button_b: (i128, i128),
+    prize: (i128, i128),
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a.abs(), if a >= 0 { 1 } else { -1 }, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// The cheapest presses of A and B, neither more than `max_presses`, that
+/// land the claw exactly on the prize, if there are any.
+fn find_solution(machine: &ClawMachine, max_presses: Option<i128>) -> Option<(i128, i128)> {
+    let (ax, ay) = machine.button_a;
+    let (bx, by) = machine.button_b;
+    let (px, py) = machine.prize;
+
+    // Two independent buttons give exactly one way to reach any point
+    // (Cramer's rule), which only counts if it is whole and pressable
+    let det = ax * by - ay * bx;
+    if det != 0 {
+        let a = px * by - py * bx;
+        let b = ax * py - ay * px;
+        if a % det != 0 || b % det != 0 {
+            return None;
+        }
+        let (a, b) = (a / det, b / det);
+        let pressable = |n| (0..=max_presses.unwrap_or(i128::MAX)).contains(&n);
+        return (pressable(a) && pressable(b)).then_some((a, b));
+    }
+
+    // Parallel buttons: solve along one axis, then make sure the other one
+    // agrees, which it does everywhere on the line or nowhere
+    let (a, b) = match (ax, bx) {
+        (0, 0) => cheapest_on_line(ay, by, py, max_presses)?,
+        _ => cheapest_on_line(ax, bx, px, max_presses)?,
+    };
+    (a * ax + b * bx == px && a * ay + b * by == py).then_some((a, b))
+}
+
+/// The cheapest `a` and `b` from 0 to `max_presses` with `a * a_step + b *
+/// b_step == target`, for steps that are not negative.
+fn cheapest_on_line(
+    a_step: i128,
+    b_step: i128,
+    target: i128,
+    max_presses: Option<i128>,
+) -> Option<(i128, i128)> {
+    let max = max_presses.unwrap_or(i128::MAX);
+    let only = |step: i128| target % step == 0 && (0..=max).contains(&(target / step));
+    match (a_step, b_step) {
+        (0, 0) => return (target == 0).then_some((0, 0)),
+        (0, _) => return only(b_step).then_some((0, target / b_step)),
+        (_, 0) => return only(a_step).then_some((target / a_step, 0)),
+        _ => {}
+    }
+
+    let g = gcd(a_step, b_step);
+    if target % g != 0 {
+        return None;
+    }
+
+    // Every solution is (a0 + k * da, b0 - k * db) for some k
+    let (_, x, y) = extended_gcd(a_step, b_step);
+    let (a0, b0) = (x * (target / g), y * (target / g));
+    let (da, db) = (b_step / g, a_step / g);
+
+    // 0 <= a <= max and 0 <= b <= max bound k from below and above
+    let k_min = (-a0.div_euclid(da)).max(-(max.saturating_sub(b0)).div_euclid(db));
+    let k_max = b0.div_euclid(db).min(max.saturating_sub(a0).div_euclid(da));
+    if k_min > k_max {
+        return None;
+    }
+
+    // The cost changes by the same amount with each step of k, so the
+    // cheapest end of the range wins
+    let k = if 3 * da - db > 0 { k_min } else { k_max };
+    Some((a0 + k * da, b0 - k * db))
+}
+
+fn parse_input(input: &str) -> Result<Vec<ClawMachine>, AocError> {
+    parse::sections(input)
+        .into_iter()
+        .map(|section| match section[..] {
+            [button_a, button_b, prize] => Ok(ClawMachine {
+                button_a: parse_button(button_a)?,
+                button_b: parse_button(button_b)?,
+                prize: parse_xy(prize)?,
+            }),
+            _ => Err(section[section.len() - 1].error(format!(
+                "expected a claw machine of 3 lines, found {}",
+                section.len()
+            ))),
+        })
+        .collect()
+}
+
+fn parse_xy(line: Line) -> Result<(i128, i128), AocError> {
+    match line.ints()?[..] {
+        [x, y] => Ok((x, y)),
+        ref values => Err(line.error(format!(
+            "expected an X and a Y value, found {} numbers",
+            values.len()
+        ))),
+    }
+}
+
+/// A button's move, which the solver needs to be forwards on both axes.
+fn parse_button(line: Line) -> Result<(i128, i128), AocError> {
+    let (x, y) = parse_xy(line)?;
+    match x < 0 || y < 0 {
+        true => Err(line.error("buttons can't move the claw backwards")),
+        false => Ok((x, y)),
+    }
+}
+
+fn calculate_tokens(a_presses: i128, b_presses: i128) -> i128 {
+    3 * a_presses + b_presses
+}
+
+fn total_tokens(machines: &[ClawMachine], max_presses: Option<i128>) -> i128 {
+    let mut total_tokens = 0;
+    let mut winnable_prizes = 0;
+
+    let solutions = parallel::map(machines, |machine| find_solution(machine, max_presses));
+    for (a, b) in solutions.into_iter().flatten() {
+        winnable_prizes += 1;
+        total_tokens += calculate_tokens(a, b);
+        common::trace!("Found solution: A={}, B={}", a, b);
+    }
+
+    common::debug!("Winnable prizes: {}", winnable_prizes);
+    total_tokens
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        total_tokens(machines, Some(MAX_PRESSES)).into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        let machines: Vec<ClawMachine> = machines
+            .iter()
+            .map(|machine| ClawMachine {
+                prize: (
+                    machine.prize.0 + PRIZE_OFFSET,
+                    machine.prize.1 + PRIZE_OFFSET,
+                ),
+                ..machine.clone()
+            })
+            .collect();
+
+        total_tokens(&machines, None).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut machines = vec![];
+        for _ in 0..size.unwrap_or(320) {
+            let a = (rng.range(10..100), rng.range(10..100));
+            let b = (rng.range(10..100), rng.range(10..100));
+
+            // Half the prizes are reachable within the press limit
+            let prize = match rng.chance(0.5) {
+                true => {
+                    let (na, nb) = (rng.range(0..101), rng.range(0..101));
+                    (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+                }
+                false => (rng.range(1000..20_000), rng.range(1000..20_000)),
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        Some(machines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{property, random::Rng, Solution};
+
+    use super::*;
+
+    common::example_tests!(super::Day13);
+
+    fn machines(rng: &mut Rng, size: usize) -> Vec<ClawMachine> {
+        Day13::parse(&Day13::generate(rng, Some(size)).unwrap()).unwrap()
+    }
+
+    /// A machine whose prize is reached by pressing A and B the given
+    /// number of times, with buttons sometimes parallel.
+    fn winnable(rng: &mut Rng, size: usize) -> (ClawMachine, (i128, i128)) {
+        let button_a = (rng.range(0..100) as i128, rng.range(0..100) as i128);
+        let button_b = match rng.chance(0.2) {
+            true => {
+                let k = rng.range(1..5) as i128;
+                (button_a.0 * k, button_a.1 * k)
+            }
+            false => (rng.range(0..100) as i128, rng.range(0..100) as i128),
+        };
+        let presses = (
+            rng.range(0..size as i64) as i128,
+            rng.range(0..size as i64) as i128,
+        );
+        let prize = (
+            presses.0 * button_a.0 + presses.1 * button_b.0,
+            presses.0 * button_a.1 + presses.1 * button_b.1,
+        );
+        let machine = ClawMachine {
+            button_a,
+            button_b,
+            prize,
+        };
+        (machine, presses)
+    }
+
+    #[test]
+    fn solutions_satisfy_both_equations() {
+        property::check(20, machines, |machines| {
+            for machine in machines {
+                for offset in [0, PRIZE_OFFSET] {
+                    let (px, py) = (machine.prize.0 + offset, machine.prize.1 + offset);
+                    let machine = ClawMachine {
+                        prize: (px, py),
+                        ..machine.clone()
+                    };
+                    if let Some((a, b)) = find_solution(&machine, None) {
+                        let ((ax, ay), (bx, by)) = (machine.button_a, machine.button_b);
+                        assert!(a >= 0 && b >= 0, "{machine:?}");
+                        assert_eq!((a * ax + b * bx, a * ay + b * by), (px, py));
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn finds_the_cheapest_way_to_a_reachable_prize() {
+        property::check(100, winnable, |(machine, (na, nb))| {
+            let ((ax, ay), (bx, by)) = (machine.button_a, machine.button_b);
+            let (px, py) = machine.prize;
+
+            // Nothing cheaper than the known presses can use more than this
+            // many A presses, and B follows from A when it moves in X
+            let cheapest = (0..=(3 * na + nb) / 3)
+                .flat_map(|a| (0..=3 * na + nb).map(move |b| (a, b)))
+                .filter(|&(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+                .min_by_key(|&(a, b)| calculate_tokens(a, b));
+
+            let found = find_solution(machine, None).map(|(a, b)| calculate_tokens(a, b));
+            assert_eq!(found, cheapest.map(|(a, b)| calculate_tokens(a, b)));
+        });
+    }
+
+    #[test]
+    fn keeps_to_the_press_limit_while_searching() {
+        // B is cheaper but needs 150 presses alone; 100 of each fits
+        let machine = ClawMachine {
+            button_a: (1, 1),
+            button_b: (2, 2),
+            prize: (300, 300),
+        };
+        assert_eq!(find_solution(&machine, None), Some((0, 150)));
+        assert_eq!(find_solution(&machine, Some(100)), Some((100, 100)));
+
+        property::check(60, winnable, |(machine, _)| {
+            let ((ax, ay), (bx, by)) = (machine.button_a, machine.button_b);
+            let (px, py) = machine.prize;
+            let max = 30;
+
+            let cheapest = (0..=max)
+                .flat_map(|a| (0..=max).map(move |b| (a, b)))
+                .filter(|&(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+                .map(|(a, b)| calculate_tokens(a, b))
+                .min();
+
+            let found = find_solution(machine, Some(max)).map(|(a, b)| calculate_tokens(a, b));
+            assert_eq!(found, cheapest);
+        });
+    }
+
+    #[test]
+    fn rejects_buttons_that_move_backwards() {
+        let error =
+            Day13::parse("Button A: X+1, Y-2\nButton B: X+3, Y+4\nPrize: X=5, Y=6\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (Some(1), "buttons can't move the claw backwards")
+        );
+    }
+}
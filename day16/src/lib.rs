@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use common::{
+    geom::{Direction4, Point},
+    random::{self, Rng},
+    render::{map_cell, Canvas, Rgb},
+    search::dijkstra,
+    Answer, AocError, Grid, Solution,
+};
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq)]
+struct State {
+    position: Point,
+    direction: Direction4,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(input)?;
+        for (tile, name) in [('S', "start"), ('E', "end")] {
+            if grid.find(&tile).is_none() {
+                return Err(AocError::new(format!(
+                    "no {} tile `{}` in the maze",
+                    name, tile
+                )));
+            }
+        }
+        Ok(grid)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        match explore(grid) {
+            Some((min_end_cost, _)) => min_end_cost.into(),
+            None => "The end is not reachable.".into(),
+        }
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        match explore(grid) {
+            Some((_, best_path_tiles)) => best_path_tiles.len().into(),
+            None => "The end is not reachable.".into(),
+        }
+    }
+
+    fn render(grid: &Self::Input) -> Option<Canvas> {
+        let mut canvas = Canvas::from_grid(grid, map_cell);
+        if let Some((_, best_path_tiles)) = explore(grid) {
+            canvas.highlight(best_path_tiles, Rgb::YELLOW);
+        }
+        canvas.highlight(grid.find(&'S'), Rgb::GREEN);
+        canvas.highlight(grid.find(&'E'), Rgb::RED);
+        Some(canvas)
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        Some(random::maze(rng, size.unwrap_or(141), 0.05).to_string())
+    }
+}
+
+/// Returns the lowest score to reach the end and the tiles on a best path.
+fn explore(grid: &Grid<char>) -> Option<(usize, HashSet<Point>)> {
+    // Find start and end positions
+    let start_pos = grid.find(&'S').unwrap_or_default();
+    let end_pos = grid.find(&'E').unwrap_or_default();
+
+    // Start facing east as per instructions
+    let initial_state = State {
+        position: start_pos,
+        direction: Direction4::Right,
+    };
+
+    let costs = dijkstra([initial_state], |current: &State| {
+        // Try moving forward
+        let forward = grid
+            .step(current.position, current.direction)
+            .filter(|&pos| grid[pos] != '#')
+            .map(|new_pos| {
+                let new_state = State {
+                    position: new_pos,
+                    direction: current.direction,
+                };
+                (new_state, 1)
+            });
+
+        // Try rotating left and right 90 degrees
+        let rotations = [
+            current.direction.turn_left(),
+            current.direction.turn_right(),
+        ]
+        .map(|new_direction| {
+            let new_state = State {
+                position: current.position,
+                direction: new_direction,
+            };
+            (new_state, 1000)
+        });
+
+        forward.into_iter().chain(rotations)
+    });
+
+    // The end can be reached facing any direction
+    let end_states = Direction4::ALL.map(|direction| State {
+        position: end_pos,
+        direction,
+    });
+    let min_end_cost = end_states.iter().filter_map(|s| costs.cost(s)).min()?;
+
+    // Mark tiles that are part of any best path
+    let best_ends = end_states
+        .into_iter()
+        .filter(|s| costs.cost(s) == Some(min_end_cost));
+    let best_path_tiles = costs
+        .nodes_on_paths_to(best_ends)
+        .into_iter()
+        .map(|state| state.position)
+        .collect();
+
+    Some((min_end_cost, best_path_tiles))
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day16);
+}
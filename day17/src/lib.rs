@@ -0,0 +1,301 @@
+use std::fmt;
+
+use common::{
+    differential::Differential,
+    parse::{self, Line},
+    random::Rng,
+    Answer, AocError, Solution,
+};
+
+pub mod part_2;
+
+pub struct Computer {
+    reg_a: i32,
+    reg_b: i32,
+    reg_c: i32,
+    program: Vec<usize>,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Computer;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let sections = parse::sections(input);
+        let [registers, program] = &sections[..] else {
+            return Err(AocError::new(
+                "expected the registers, a blank line and the program",
+            ));
+        };
+
+        let [reg_a, reg_b, reg_c] = registers[..] else {
+            return Err(registers[0].error(format!(
+                "expected registers A, B and C, found {} lines",
+                registers.len()
+            )));
+        };
+        let [program] = program[..] else {
+            return Err(program[1].error("expected the program on a single line"));
+        };
+
+        Ok(Computer {
+            reg_a: parse_register(reg_a)?,
+            reg_b: parse_register(reg_b)?,
+            reg_c: parse_register(reg_c)?,
+            program: parse_program(program)?,
+        })
+    }
+
+    fn part1(computer: &Self::Input) -> Answer {
+        run(computer)
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            .into()
+    }
+
+    fn part2(computer: &Self::Input) -> Answer {
+        match part_2::solve(&computer.program) {
+            Some(initial_a) => initial_a.into(),
+            None => "No initial value for register A reproduces the program".into(),
+        }
+    }
+}
+
+fn run(computer: &Computer) -> Vec<i32> {
+    let program = &computer.program;
+    let mut reg_a = computer.reg_a;
+    let mut reg_b = computer.reg_b;
+    let mut reg_c = computer.reg_c;
+
+    let mut ip = 0;
+    let mut output = Vec::new();
+
+    while ip < program.len() {
+        let opcode = program[ip];
+        if ip + 1 >= program.len() {
+            break;
+        }
+        let operand = program[ip + 1];
+        match opcode {
+            0 => {
+                // adv: reg_a = reg_a / (2 ^ combo_operand)
+                let exponent = combo_operand_value(operand, reg_a, reg_b, reg_c);
+                let denominator = 2_i32.pow(exponent as u32);
+                if denominator != 0 {
+                    reg_a /= denominator;
+                } else {
+                    reg_a = 0;
+                }
+                ip += 2;
+            }
+            1 => {
+                // bxl: reg_b ^= literal_operand
+                reg_b ^= operand as i32;
+                ip += 2;
+            }
+            2 => {
+                // bst: reg_b = combo_operand % 8
+                reg_b = combo_operand_value(operand, reg_a, reg_b, reg_c) % 8;
+                ip += 2;
+            }
+            3 => {
+                // jnz: if reg_a != 0, ip = literal_operand
+                if reg_a != 0 {
+                    ip = operand;
+                } else {
+                    ip += 2;
+                }
+            }
+            4 => {
+                // bxc: reg_b ^= reg_c
+                reg_b ^= reg_c;
+                ip += 2;
+            }
+            5 => {
+                // out: output combo_operand % 8
+                let value = combo_operand_value(operand, reg_a, reg_b, reg_c) % 8;
+                output.push(value);
+                ip += 2;
+            }
+            6 => {
+                // bdv: reg_b = reg_a / (2 ^ combo_operand)
+                let exponent = combo_operand_value(operand, reg_a, reg_b, reg_c);
+                let denominator = 2_i32.pow(exponent as u32);
+                if denominator != 0 {
+                    reg_b = reg_a / denominator;
+                } else {
+                    reg_b = 0;
+                }
+                ip += 2;
+            }
+            7 => {
+                // cdv: reg_c = reg_a / (2 ^ combo_operand)
+                let exponent = combo_operand_value(operand, reg_a, reg_b, reg_c);
+                let denominator = 2_i32.pow(exponent as u32);
+                if denominator != 0 {
+                    reg_c = reg_a / denominator;
+                } else {
+                    reg_c = 0;
+                }
+                ip += 2;
+            }
+            _ => {
+                break;
+            }
+        }
+    }
+
+    output
+}
+
+fn parse_register(line: Line) -> Result<i32, AocError> {
+    let (_, value) = line.key_value()?;
+    line.parse(value)
+}
+
+fn parse_program(line: Line) -> Result<Vec<usize>, AocError> {
+    let (_, values) = line.key_value()?;
+
+    let mut program = Vec::new();
+    for (token, v) in values.split(',').zip(line.comma_list::<usize>(values)?) {
+        if v > 7 {
+            return Err(line.token_error(token, "not a 3-bit number"));
+        }
+        // adv, bst, out, bdv and cdv take a combo operand, where 7 is reserved
+        let takes_combo =
+            program.len() % 2 == 1 && matches!(program.last(), Some(0 | 2 | 5 | 6 | 7));
+        if takes_combo && v == 7 {
+            return Err(line.token_error(token, "reserved combo operand"));
+        }
+        program.push(v);
+    }
+    Ok(program)
+}
+
+fn combo_operand_value(operand: usize, reg_a: i32, reg_b: i32, reg_c: i32) -> i32 {
+    match operand {
+        0..=3 => operand as i32,
+        4 => reg_a,
+        5 => reg_b,
+        6 => reg_c,
+        _ => panic!("Invalid combo operand"),
+    }
+}
+
+/// A program run from the given value of A, with B and C at zero.
+pub struct Start {
+    program: Vec<usize>,
+    reg_a: i32,
+}
+
+impl fmt::Display for Start {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let program: Vec<String> = self.program.iter().map(usize::to_string).collect();
+        writeln!(
+            f,
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n",
+            self.reg_a
+        )?;
+        write!(f, "Program: {}", program.join(","))
+    }
+}
+
+/// Whether the program is a single loop that ends in `jnz 0` and shifts A
+/// right each time round, so it always halts.
+fn halts(program: &[usize]) -> bool {
+    let pairs: Vec<&[usize]> = program.chunks(2).collect();
+    pairs.last() == Some(&&[3, 0][..])
+        && pairs.iter().filter(|pair| pair[0] == 3).count() == 1
+        && pairs
+            .iter()
+            .any(|pair| pair[0] == 0 && (1..=3).contains(&pair[1]))
+}
+
+/// The part 1 interpreter (`i32`, `%`) against the one part 2 searches with
+/// (`i64`, `rem_euclid`, exponents checked), on programs shaped like the
+/// real one.
+pub struct Interpreters;
+
+impl Differential for Interpreters {
+    const DAY: u8 = 17;
+    const NAME: &'static str = "interpreters";
+    const MAX_SIZE: usize = 10;
+
+    type Input = Start;
+    type Output = Vec<i64>;
+
+    fn reference(start: &Start) -> Vec<i64> {
+        let computer = Computer {
+            reg_a: start.reg_a,
+            reg_b: 0,
+            reg_c: 0,
+            program: start.program.clone(),
+        };
+        run(&computer).into_iter().map(i64::from).collect()
+    }
+
+    fn fast(start: &Start) -> Vec<i64> {
+        part_2::run(&start.program, start.reg_a as i64)
+    }
+
+    /// `size` is the number of octal digits in A.
+    fn generate(rng: &mut Rng, size: usize) -> Start {
+        // bxl, bst, bxc, out, bdv and cdv in any order around a single adv
+        let mut program = vec![];
+        for _ in 0..rng.range(1..6) {
+            let opcode = *rng.pick(&[1, 2, 4, 5, 6, 7]);
+            let operand = match opcode {
+                1 | 4 => rng.below(8),
+                _ => rng.below(7),
+            };
+            program.extend([opcode, operand]);
+        }
+        let adv = 2 * rng.below(program.len() / 2 + 1);
+        program.splice(adv..adv, [0, 1 + rng.below(3)]);
+        program.extend([3, 0]);
+
+        let digits = size.clamp(1, 10) as u32;
+        let reg_a = rng.range(0..8_i64.pow(digits).min(i32::MAX as i64)) as i32;
+        Start { program, reg_a }
+    }
+
+    fn shrink(start: &Start) -> Vec<Start> {
+        let mut smaller = vec![];
+        let a = start.reg_a;
+        for reg_a in [0, a / 8, a / 2, a - 1] {
+            if (0..a).contains(&reg_a) {
+                smaller.push(Start {
+                    program: start.program.clone(),
+                    reg_a,
+                });
+            }
+        }
+
+        // Drop an instruction, or lower an operand
+        for i in (0..start.program.len()).step_by(2) {
+            let mut program = start.program.clone();
+            program.drain(i..i + 2);
+            smaller.push(Start { program, reg_a: a });
+        }
+        for i in (1..start.program.len()).step_by(2) {
+            if start.program[i] > 0 {
+                let mut program = start.program.clone();
+                program[i] -= 1;
+                smaller.push(Start { program, reg_a: a });
+            }
+        }
+
+        smaller.retain(|start| halts(&start.program));
+        smaller
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day17);
+}
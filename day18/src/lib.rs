@@ -0,0 +1,236 @@
+use common::{
+    animate::Recorder,
+    geom::Point,
+    parse,
+    random::{self, Rng},
+    render::{Canvas, Cell, Rgb},
+    search::astar,
+    solution::Settings,
+    Answer, AocError, Grid, Solution,
+};
+
+const SIZE: usize = 71;
+const FIRST_BYTES: usize = 1024;
+
+pub struct Day18;
+
+/// The bytes in the order they fall, and the memory space they fall into.
+/// The statement's example has a smaller space and counts fewer bytes for
+/// part 1; its `size` and `bytes` settings say so.
+pub struct Memory {
+    size: usize,
+    first_bytes: usize,
+    bytes: Vec<(usize, usize)>,
+}
+
+impl Memory {
+    /// The space with the first `count` bytes fallen, `true` where corrupted.
+    fn after(&self, count: usize) -> Grid<bool> {
+        let mut grid = Grid::new(self.size, self.size, false);
+        for &(x, y) in self.bytes.iter().take(count) {
+            grid[Point::new(y, x)] = true;
+        }
+        grid
+    }
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let bytes = parse::lines(input)
+            .map(|line| match line.comma_list::<usize>(line.text)?[..] {
+                [x, y] if x < SIZE && y < SIZE => Ok((x, y)),
+                [_, _] => Err(line.error(format!(
+                    "byte falls outside the {}x{} memory space",
+                    SIZE, SIZE
+                ))),
+                _ => Err(line.error("expected `x,y`")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Memory {
+            size: SIZE,
+            first_bytes: FIRST_BYTES,
+            bytes,
+        })
+    }
+
+    fn configure(memory: &mut Self::Input, settings: &Settings) -> Result<(), AocError> {
+        for (name, &value) in settings {
+            match name.as_str() {
+                "size" if value == 0 => {
+                    return Err(AocError::new("the memory space needs a size of at least 1"))
+                }
+                "size" => memory.size = value,
+                "bytes" => memory.first_bytes = value,
+                _ => return Err(AocError::new(format!("unknown setting `{name}`"))),
+            }
+        }
+        match memory
+            .bytes
+            .iter()
+            .position(|&(x, y)| x.max(y) >= memory.size)
+        {
+            Some(i) => Err(AocError::new(format!(
+                "byte {} falls outside the {}x{} memory space",
+                i + 1,
+                memory.size,
+                memory.size
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        match shortest_path(&memory.after(memory.first_bytes)) {
+            Some((_, steps)) => steps.into(),
+            None => "The exit is not reachable.".into(),
+        }
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        let mut grid = memory.after(0);
+
+        for &(x, y) in &memory.bytes {
+            grid[Point::new(y, x)] = true; // Mark as corrupted
+
+            // Check if there is still a path from corner to corner
+            if shortest_path(&grid).is_none() {
+                return format!("{},{}", x, y).into();
+            }
+        }
+
+        "The exit is always reachable.".into()
+    }
+
+    fn render(memory: &Self::Input) -> Option<Canvas> {
+        let grid = memory.after(memory.first_bytes);
+        let mut canvas = Canvas::from_grid(&grid, memory_cell);
+        if let Some((path, _)) = shortest_path(&grid) {
+            canvas.path(&path, Rgb::YELLOW);
+        }
+        Some(canvas)
+    }
+
+    fn animate(memory: &Self::Input, recorder: &mut Recorder) {
+        let mut grid = memory.after(0);
+        let mut path = shortest_path(&grid).map(|(path, _)| path);
+
+        let draw = |grid: &Grid<bool>, path: &Option<Vec<Point>>| {
+            let mut canvas = Canvas::from_grid(grid, memory_cell);
+            if let Some(path) = path {
+                canvas.path(path, Rgb::YELLOW);
+            }
+            canvas
+        };
+
+        for &(x, y) in &memory.bytes {
+            let byte = Point::new(y, x);
+            grid[byte] = true;
+
+            // The path only needs finding again when a byte lands on it
+            if path.as_ref().is_some_and(|path| path.contains(&byte)) {
+                path = shortest_path(&grid).map(|(path, _)| path);
+            }
+            if path.is_none() {
+                let mut canvas = draw(&grid, &path);
+                canvas.highlight([byte], Rgb::RED);
+                recorder.frame(|| canvas.clone());
+                recorder.finish(|| canvas);
+                return;
+            }
+            recorder.frame(|| draw(&grid, &path));
+        }
+        recorder.finish(|| draw(&grid, &path));
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        // The walls of a maze fall first, in random order, so the exit stays
+        // reachable until they are all down; then the rest of the cells but
+        // the corners, until it is cut off. The maze has a border one cell
+        // outside the memory space.
+        let maze = random::maze(rng, SIZE + 2, 0.0);
+        let (start, end) = (Point::new(0, 0), Point::new(SIZE - 1, SIZE - 1));
+        let mut walls = vec![];
+        let mut open = vec![];
+        for pos in Grid::new(SIZE, SIZE, ()).positions() {
+            match maze[Point::new(pos.row + 1, pos.col + 1)] {
+                '#' => walls.push(pos),
+                _ if pos != start && pos != end => open.push(pos),
+                _ => {}
+            }
+        }
+        rng.shuffle(&mut walls);
+        rng.shuffle(&mut open);
+
+        let lines: Vec<String> = walls
+            .iter()
+            .chain(&open)
+            .take(size.unwrap_or(usize::MAX))
+            .map(|pos| format!("{},{}\n", pos.col, pos.row))
+            .collect();
+        Some(lines.concat())
+    }
+}
+
+fn memory_cell(&corrupted: &bool) -> Cell {
+    match corrupted {
+        true => Cell::new('#', Rgb::BLACK, Rgb::GREY),
+        false => Cell::new('.', Rgb::WHITE, Rgb::BLACK),
+    }
+}
+
+/// A shortest path from the top-left to the bottom-right corner and its
+/// number of steps, if there is one.
+fn shortest_path(grid: &Grid<bool>) -> Option<(Vec<Point>, usize)> {
+    let (start, end) = (
+        Point::new(0, 0),
+        Point::new(grid.height() - 1, grid.width() - 1),
+    );
+    if grid[start] {
+        return None;
+    }
+
+    astar(
+        start,
+        |&pos| {
+            grid.neighbors4(pos)
+                .filter(|&next| !grid[next])
+                .map(|next| (next, 1))
+        },
+        |pos| pos.manhattan(end),
+        |&pos| pos == end,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(super::Day18);
+
+    #[test]
+    fn rejects_settings_that_do_not_fit() {
+        let configure = |name: &str, value| {
+            let mut memory = Day18::parse("5,4\n").unwrap();
+            Day18::configure(&mut memory, &Settings::from([(name.to_string(), value)]))
+                .map_err(|e| e.message)
+        };
+
+        assert_eq!(configure("size", 6), Ok(()));
+        assert_eq!(
+            configure("size", 0),
+            Err("the memory space needs a size of at least 1".to_string())
+        );
+        assert_eq!(
+            configure("size", 5),
+            Err("byte 1 falls outside the 5x5 memory space".to_string())
+        );
+        assert_eq!(
+            configure("speed", 1),
+            Err("unknown setting `speed`".to_string())
+        );
+    }
+}
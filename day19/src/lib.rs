@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use common::{parallel, parse, random::Rng, Answer, AocError, Solution};
+
+fn count_ways(design: &str, allowed_patterns: &HashSet<&str>) -> usize {
+    let n = design.len();
+    let chars = design.as_bytes();
+    let mut dp = vec![0usize; n + 1];
+    dp[0] = 1;
+
+    for i in 1..=n {
+        for pattern in allowed_patterns {
+            let plen = pattern.len();
+            if i >= plen && &chars[i - plen..i] == pattern.as_bytes() {
+                dp[i] += dp[i - plen];
+            }
+        }
+    }
+
+    dp[n]
+}
+
+#[derive(Debug)]
+pub struct Towels {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Towels {
+    /// The number of ways to make each design, in order.
+    fn ways(&self) -> Vec<usize> {
+        let allowed_patterns: HashSet<&str> = self.patterns.iter().map(String::as_str).collect();
+        parallel::map(&self.designs, |design| {
+            count_ways(design, &allowed_patterns)
+        })
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = Towels;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let sections = parse::sections(input);
+        let (patterns, designs) =
+            match &sections[..] {
+                [patterns, designs @ ..] if patterns.len() == 1 => (patterns[0], designs),
+                _ => return Err(AocError::new(
+                    "expected the towel patterns on one line, then a blank line and the designs",
+                )),
+            };
+
+        // An empty pattern would match everywhere and inflate every count, so
+        // `comma_list` rejecting empty items matters here
+        let patterns = patterns.comma_list(patterns.text)?;
+        let designs = designs
+            .iter()
+            .flatten()
+            .map(|line| line.text.trim().to_string())
+            .collect();
+
+        Ok(Towels { patterns, designs })
+    }
+
+    fn part1(towels: &Self::Input) -> Answer {
+        towels
+            .ways()
+            .into_iter()
+            .filter(|&ways| ways > 0)
+            .count()
+            .into()
+    }
+
+    fn part2(towels: &Self::Input) -> Answer {
+        towels.ways().into_iter().sum::<usize>().into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let designs = size.unwrap_or(400).max(1);
+
+        // One colour never appears in a pattern, so designs with it in are
+        // impossible however close the rest comes
+        let mut colours = ['w', 'u', 'b', 'r', 'g'];
+        rng.shuffle(&mut colours);
+        let (colours, missing) = (&colours[..4], colours[4]);
+
+        let mut patterns: Vec<String> = vec![];
+        while patterns.len() < designs.clamp(10, 1000) {
+            let pattern: String = (0..rng.range(1..9)).map(|_| *rng.pick(colours)).collect();
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        }
+
+        let mut input = format!("{}\n\n", patterns.join(", "));
+        for _ in 0..designs {
+            let mut design: Vec<char> = (0..rng.range(3..11))
+                .flat_map(|_| rng.pick(&patterns).chars())
+                .collect();
+            if rng.chance(0.4) {
+                let i = rng.below(design.len());
+                design[i] = missing;
+            }
+            input.extend(design);
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{property, random::Rng, Solution};
+
+    use super::*;
+
+    common::example_tests!(super::Day19);
+
+    /// Generated towels with designs cut short, so counts stay well inside
+    /// a `usize` when two are joined.
+    fn towels(rng: &mut Rng, size: usize) -> Towels {
+        let mut towels = Day19::parse(&Day19::generate(rng, Some(size)).unwrap()).unwrap();
+        for design in &mut towels.designs {
+            design.truncate(20);
+        }
+        towels
+    }
+
+    #[test]
+    fn joined_designs_have_at_least_the_product_of_ways() {
+        property::check(12, towels, |towels| {
+            let patterns: HashSet<&str> = towels.patterns.iter().map(String::as_str).collect();
+            for (first, second) in towels.designs.iter().zip(towels.designs.iter().rev()) {
+                let joined = format!("{first}{second}");
+                let product = count_ways(first, &patterns) * count_ways(second, &patterns);
+                assert!(count_ways(&joined, &patterns) >= product, "{joined}");
+            }
+        });
+    }
+}
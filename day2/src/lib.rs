@@ -0,0 +1,594 @@
+/*
+a report only counts as safe if both of the following are true:
+- The levels are either all increasing or all decreasing.
+- Any two adjacent levels differ by at least one and at most three.
+
+7 6 4 2 1: Safe because the levels are all decreasing by 1 or 2.
+1 2 7 8 9: Unsafe because 2 7 is an increase of 5.
+9 7 6 2 1: Unsafe because 6 2 is a decrease of 4.
+1 3 2 4 5: Unsafe because 1 3 is increasing but 3 2 is decreasing.
+8 6 4 4 1: Unsafe because 4 4 is neither an increase or a decrease.
+1 3 6 7 9: Safe because the levels are all increasing by 1, 2, or 3.
+*/
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt, mem,
+    ops::RangeInclusive,
+    sync::Arc,
+};
+
+use common::{parse, random::Rng, Answer, AocError, Solution};
+
+/// Whether the levels of a report have to keep going one way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonic {
+    /// Every step goes the same way, so no plateaus.
+    Strict,
+    /// Never both up and down; plateaus are up to the rules.
+    NonStrict,
+    /// Up and down as they like.
+    Off,
+}
+
+type Holds = Arc<dyn Fn(&[i32]) -> bool + Send + Sync>;
+
+/// A named check on every run of `size` adjacent levels.
+#[derive(Clone)]
+struct Window {
+    name: String,
+    size: usize,
+    holds: Holds,
+}
+
+/// What makes a report safe. The default is the puzzle's: strictly rising
+/// or falling, by 1 to 3 a step.
+#[derive(Clone)]
+pub struct Rules {
+    steps: RangeInclusive<i32>,
+    plateaus: bool,
+    monotonic: Monotonic,
+    windows: Vec<Window>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            steps: 1..=3,
+            plateaus: false,
+            monotonic: Monotonic::Strict,
+            windows: Vec::new(),
+        }
+    }
+}
+
+impl Rules {
+    /// How far adjacent levels may be apart, up or down, when they differ.
+    pub fn with_steps(mut self, steps: RangeInclusive<i32>) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    /// Whether adjacent levels may be equal. Allowing them relaxes
+    /// [`Monotonic::Strict`] to [`Monotonic::NonStrict`], as strict rules
+    /// them out.
+    pub fn with_plateaus(mut self, plateaus: bool) -> Self {
+        self.plateaus = plateaus;
+        if plateaus && self.monotonic == Monotonic::Strict {
+            self.monotonic = Monotonic::NonStrict;
+        }
+        self
+    }
+
+    /// [`Monotonic::Strict`] rules plateaus out again.
+    pub fn with_monotonic(mut self, monotonic: Monotonic) -> Self {
+        self.monotonic = monotonic;
+        if monotonic == Monotonic::Strict {
+            self.plateaus = false;
+        }
+        self
+    }
+
+    /// Also requires `holds` of every `size` adjacent levels, e.g. that no
+    /// three in a row change by more than 5 in all. Reports shorter than
+    /// `size` pass. `name` is what diagnostics call it.
+    pub fn with_window(
+        mut self,
+        name: impl Into<String>,
+        size: usize,
+        holds: impl Fn(&[i32]) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        assert!(size > 0, "a window needs at least one level");
+        self.windows.push(Window {
+            name: name.into(),
+            size,
+            holds: Arc::new(holds),
+        });
+        self
+    }
+}
+
+impl Rules {
+    /// Whether one step between adjacent kept levels is allowed, going in
+    /// `direction` (1 up, -1 down) or either way for `None`.
+    fn step_ok(&self, diff: i32, direction: Option<i32>) -> bool {
+        match diff {
+            0 => self.plateaus,
+            _ => {
+                self.steps.contains(&diff.abs())
+                    && direction.is_none_or(|direction| diff.signum() == direction)
+            }
+        }
+    }
+
+    /// The directions a safe report may go in, each checked on its own.
+    fn directions(&self) -> Vec<Option<i32>> {
+        match self.monotonic {
+            Monotonic::Off => vec![None],
+            _ => vec![Some(1), Some(-1)],
+        }
+    }
+
+    /// Whether every window ending at `next` holds, after the kept levels
+    /// `tail` (indices into `report`).
+    fn windows_hold(&self, report: &[i32], tail: &[usize], next: usize) -> bool {
+        self.windows.iter().all(|window| {
+            let Some(start) = (tail.len() + 1).checked_sub(window.size) else {
+                return true;
+            };
+            let levels: Vec<i32> = tail[start..]
+                .iter()
+                .chain([&next])
+                .map(|&i| report[i])
+                .collect();
+            (window.holds)(&levels)
+        })
+    }
+}
+
+/// The first rule a report breaks, reading from the start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the first level involved.
+    pub at: usize,
+    /// The levels that break the rule: two adjacent ones, or a whole window.
+    pub levels: Vec<i32>,
+    pub kind: ViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// Adjacent levels further apart than the rules allow.
+    TooBig,
+    /// Adjacent levels closer than the rules allow, but not equal.
+    TooSmall,
+    /// Adjacent levels that are equal.
+    Plateau,
+    /// A step the other way from `earlier`, the first step that went up or
+    /// down.
+    DirectionChange { earlier: [i32; 2] },
+    /// A custom window that didn't hold, by name.
+    Window(String),
+}
+
+/// Says what went wrong like the puzzle does, e.g. "2 7 is an increase of 5".
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let levels: Vec<String> = self.levels.iter().map(i32::to_string).collect();
+        let levels = levels.join(" ");
+        let change = |[from, to]: [i32; 2]| match to - from {
+            diff if diff > 0 => ("an increase", "increasing", diff),
+            diff => ("a decrease", "decreasing", -diff),
+        };
+
+        match &self.kind {
+            ViolationKind::TooBig => {
+                let (change, _, by) = change([self.levels[0], self.levels[1]]);
+                write!(f, "{levels} is {change} of {by}")
+            }
+            ViolationKind::TooSmall => {
+                let (change, _, by) = change([self.levels[0], self.levels[1]]);
+                write!(f, "{levels} is {change} of only {by}")
+            }
+            ViolationKind::Plateau => {
+                write!(f, "{levels} is neither an increase nor a decrease")
+            }
+            ViolationKind::DirectionChange { earlier } => {
+                let (_, was, _) = change(*earlier);
+                let (_, now, _) = change([self.levels[0], self.levels[1]]);
+                write!(
+                    f,
+                    "{} {} is {was} but {levels} is {now}",
+                    earlier[0], earlier[1]
+                )
+            }
+            ViolationKind::Window(name) => write!(f, "{levels} breaks {name}"),
+        }
+    }
+}
+
+/// Checks `report` against `rules`, returning the first rule it breaks:
+/// the one whose levels end earliest, steps before custom windows.
+pub fn is_safe(report: &[i32], rules: &Rules) -> Result<(), Violation> {
+    let mut earlier: Option<[i32; 2]> = None;
+    for (end, &level) in report.iter().enumerate() {
+        if let Some(start) = end.checked_sub(1) {
+            let pair = [report[start], level];
+            let diff = level - report[start];
+            let kind = if diff == 0 {
+                (!rules.plateaus || rules.monotonic == Monotonic::Strict)
+                    .then_some(ViolationKind::Plateau)
+            } else if diff.abs() > *rules.steps.end() {
+                Some(ViolationKind::TooBig)
+            } else if diff.abs() < *rules.steps.start() {
+                Some(ViolationKind::TooSmall)
+            } else {
+                let first = *earlier.get_or_insert(pair);
+                let turned = (first[1] - first[0]).signum() != diff.signum();
+                (turned && rules.monotonic != Monotonic::Off)
+                    .then_some(ViolationKind::DirectionChange { earlier: first })
+            };
+            if let Some(kind) = kind {
+                return Err(Violation {
+                    at: start,
+                    levels: pair.to_vec(),
+                    kind,
+                });
+            }
+        }
+
+        for window in &rules.windows {
+            let Some(start) = (end + 1).checked_sub(window.size) else {
+                continue;
+            };
+            let levels = &report[start..=end];
+            if !(window.holds)(levels) {
+                return Err(Violation {
+                    at: start,
+                    levels: levels.to_vec(),
+                    kind: ViolationKind::Window(window.name.clone()),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// How a report fares, with the dampener if it needs it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnosis {
+    Safe,
+    /// Breaks the rules as it is, but not once the dampener drops these
+    /// levels, given as index and level.
+    Dampened {
+        violation: Violation,
+        dropped: Vec<(usize, i32)>,
+    },
+    Unsafe(Violation),
+}
+
+/// In the puzzle's words, e.g. "Unsafe because 6 2 is a decrease of 4".
+/// Levels are numbered from 1.
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnosis::Safe => write!(f, "Safe"),
+            Diagnosis::Dampened { violation, dropped } => {
+                let dropped: Vec<String> = dropped
+                    .iter()
+                    .map(|(i, level)| format!("level {} ({level})", i + 1))
+                    .collect();
+                write!(
+                    f,
+                    "Safe by removing {}, as {violation}",
+                    dropped.join(" and ")
+                )
+            }
+            Diagnosis::Unsafe(violation) => write!(f, "Unsafe because {violation}"),
+        }
+    }
+}
+
+/// Checks `report` against `rules`, and if it breaks them, whether
+/// dropping up to `dampener` levels fixes it.
+pub fn diagnose(report: &[i32], rules: &Rules, dampener: usize) -> Diagnosis {
+    let Err(violation) = is_safe(report, rules) else {
+        return Diagnosis::Safe;
+    };
+    match dampen(report, rules, dampener) {
+        Some(dropped) => Diagnosis::Dampened {
+            violation,
+            dropped: dropped.into_iter().map(|i| (i, report[i])).collect(),
+        },
+        None => Diagnosis::Unsafe(violation),
+    }
+}
+
+/// The fewest levels, no more than `k`, to drop from `report` to make it
+/// safe, as indices in increasing order: empty if it is safe already and
+/// `None` if it takes more than `k`. Among equally short ways, the one
+/// dropping the earliest levels.
+///
+/// Takes time linear in the report for a given `k` and window size.
+pub fn dampen(report: &[i32], rules: &Rules, k: usize) -> Option<Vec<usize>> {
+    if report.is_empty() {
+        return Some(vec![]);
+    }
+    rules
+        .directions()
+        .into_iter()
+        .filter_map(|direction| dampen_towards(report, rules, k, direction))
+        .min_by_key(|dropped| (dropped.len(), dropped.clone()))
+}
+
+fn dampen_towards(
+    report: &[i32],
+    rules: &Rules,
+    k: usize,
+    direction: Option<i32>,
+) -> Option<Vec<usize>> {
+    let n = report.len();
+    let tail_len = rules
+        .windows
+        .iter()
+        .map(|window| window.size - 1)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    // Ways to keep a level, by its index: the last few levels kept before it
+    // (all the checks still to come look at) and the fewest dropped so far
+    let mut kept: Vec<HashMap<Vec<usize>, Vec<usize>>> = vec![HashMap::new(); n];
+    for (first, ways) in kept.iter_mut().enumerate().take(k + 1) {
+        if rules.windows_hold(report, &[], first) {
+            keep_fewest(ways, vec![first], (0..first).collect());
+        }
+    }
+
+    let mut best: Option<Vec<usize>> = None;
+    for i in 0..n {
+        for (tail, dropped) in mem::take(&mut kept[i]) {
+            let spare = k - dropped.len();
+            if n - 1 - i <= spare {
+                let done: Vec<usize> = dropped.iter().copied().chain(i + 1..n).collect();
+                if best
+                    .as_ref()
+                    .is_none_or(|best| (done.len(), &done) < (best.len(), best))
+                {
+                    best = Some(done);
+                }
+            }
+
+            for next in i + 1..n.min(i + 2 + spare) {
+                if !rules.step_ok(report[next] - report[i], direction)
+                    || !rules.windows_hold(report, &tail, next)
+                {
+                    continue;
+                }
+                let mut next_tail = tail.clone();
+                next_tail.push(next);
+                if next_tail.len() > tail_len {
+                    next_tail.remove(0);
+                }
+                let mut next_dropped = dropped.clone();
+                next_dropped.extend(i + 1..next);
+                keep_fewest(&mut kept[next], next_tail, next_dropped);
+            }
+        }
+    }
+    best
+}
+
+fn keep_fewest(ways: &mut HashMap<Vec<usize>, Vec<usize>>, tail: Vec<usize>, dropped: Vec<usize>) {
+    match ways.entry(tail) {
+        Entry::Occupied(mut way) => {
+            if (dropped.len(), &dropped) < (way.get().len(), way.get()) {
+                way.insert(dropped);
+            }
+        }
+        Entry::Vacant(way) => {
+            way.insert(dropped);
+        }
+    }
+}
+
+/// How many reports are safe under `rules` with up to `dampener` levels
+/// dropped from each.
+pub fn validate_lines(lines: &[Vec<i32>], rules: &Rules, dampener: usize) -> usize {
+    lines
+        .iter()
+        .filter(|&line| dampen(line, rules, dampener).is_some())
+        .count()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse::lines(input).map(|line| line.fields()).collect()
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        let rules = Rules::default();
+        lines
+            .iter()
+            .filter(|line| is_safe(line, &rules).is_ok())
+            .count()
+            .into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        validate_lines(lines, &Rules::default(), 1).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let mut input = String::new();
+        for _ in 0..size.unwrap_or(1000) {
+            let direction = *rng.pick(&[-1, 1]);
+            let mut level = rng.range(25..75);
+            let mut levels = vec![];
+            for _ in 0..rng.range(5..9) {
+                levels.push(level);
+                level += direction * rng.range(1..4);
+            }
+
+            // About a third of reports get one level that may break them
+            if rng.chance(0.3) {
+                let i = rng.below(levels.len());
+                levels[i] = rng.range(1..100);
+            }
+
+            let levels: Vec<String> = levels.iter().map(i64::to_string).collect();
+            input.push_str(&levels.join(" "));
+            input.push('\n');
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{property, random::Rng, Solution};
+
+    use super::*;
+
+    common::example_tests!(super::Day2);
+
+    fn reports(rng: &mut Rng, size: usize) -> Vec<Vec<i32>> {
+        Day2::parse(&Day2::generate(rng, Some(size)).unwrap()).unwrap()
+    }
+
+    /// The fewest drops found by trying every way to remove up to `k`
+    /// levels, shortest first.
+    fn remove_and_retry(report: &[i32], rules: &Rules, k: usize) -> Option<usize> {
+        (0..=k.min(report.len())).find(|&drops| {
+            (0..1usize << report.len())
+                .filter(|mask| mask.count_ones() as usize == drops)
+                .any(|mask| {
+                    let kept: Vec<i32> = (0..report.len())
+                        .filter(|i| mask & (1 << i) == 0)
+                        .map(|i| report[i])
+                        .collect();
+                    is_safe(&kept, rules).is_ok()
+                })
+        })
+    }
+
+    #[test]
+    fn dampener_never_makes_a_safe_report_unsafe() {
+        let rules = Rules::default();
+        property::check(50, reports, |reports| {
+            for report in reports
+                .iter()
+                .filter(|report| is_safe(report, &rules).is_ok())
+            {
+                assert_eq!(dampen(report, &rules, 1), Some(vec![]), "{report:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn dampener_drops_as_few_levels_as_retrying_every_removal() {
+        let rules = [
+            Rules::default(),
+            Rules::default()
+                .with_plateaus(true)
+                .with_monotonic(Monotonic::NonStrict)
+                .with_window("gentle", 3, |w| (w[0] - w[2]).abs() <= 4),
+        ];
+        property::check(20, reports, |reports| {
+            for (report, rules, k) in reports
+                .iter()
+                .flat_map(|report| rules.iter().map(move |rules| (report, rules)))
+                .flat_map(|(report, rules)| (0..3).map(move |k| (report, rules, k)))
+            {
+                let dropped = dampen(report, rules, k);
+                assert_eq!(
+                    dropped.as_ref().map(Vec::len),
+                    remove_and_retry(report, rules, k),
+                    "{report:?}, k = {k}"
+                );
+                if let Some(dropped) = dropped {
+                    let kept: Vec<i32> = (0..report.len())
+                        .filter(|i| !dropped.contains(i))
+                        .map(|i| report[i])
+                        .collect();
+                    assert_eq!(
+                        is_safe(&kept, rules),
+                        Ok(()),
+                        "{report:?} without {dropped:?}"
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn rules_can_be_loosened_and_extended() {
+        let reports = [vec![1, 3, 3, 8, 9], vec![9, 4, 4, 3, 1], vec![1, 5, 2, 6]];
+        let safe = |rules: &Rules| {
+            reports
+                .iter()
+                .map(|r| is_safe(r, rules).is_ok())
+                .collect::<Vec<_>>()
+        };
+
+        let strict = Rules::default().with_steps(1..=5);
+        assert_eq!(safe(&strict), [false, false, false]);
+
+        // Plateaus can't be strictly monotonic, so allowing them relaxes it
+        let loose = strict.with_plateaus(true);
+        assert_eq!(safe(&loose), [true, true, false]);
+        assert_eq!(
+            safe(&loose.clone().with_monotonic(Monotonic::Strict)),
+            [false, false, false]
+        );
+        assert_eq!(validate_lines(&reports, &loose, 1), 3);
+        assert_eq!(dampen(&reports[2], &loose, 1), Some(vec![1]));
+
+        let loose = loose.with_monotonic(Monotonic::Off);
+        assert_eq!(safe(&loose), [true, true, true]);
+
+        // No three levels in a row spanning more than 5
+        let smooth = loose.with_window("smooth", 3, |w| {
+            w.iter().max().unwrap() - w.iter().min().unwrap() <= 5
+        });
+        assert_eq!(safe(&smooth), [false, true, true]);
+    }
+
+    #[test]
+    fn explains_each_report_like_the_puzzle() {
+        let reports = Day2::parse(include_str!("../examples/1.txt")).unwrap();
+        let rules = Rules::default();
+
+        let undampened: Vec<String> = reports
+            .iter()
+            .map(|report| diagnose(report, &rules, 0).to_string())
+            .collect();
+        assert_eq!(
+            undampened,
+            [
+                "Safe",
+                "Unsafe because 2 7 is an increase of 5",
+                "Unsafe because 6 2 is a decrease of 4",
+                "Unsafe because 1 3 is increasing but 3 2 is decreasing",
+                "Unsafe because 4 4 is neither an increase nor a decrease",
+                "Safe",
+            ]
+        );
+
+        assert_eq!(
+            diagnose(&reports[3], &rules, 1).to_string(),
+            "Safe by removing level 2 (3), as 1 3 is increasing but 3 2 is decreasing"
+        );
+        let smooth = rules.with_window("smooth", 3, |w| (w[0] - w[2]).abs() <= 4);
+        assert_eq!(
+            is_safe(&[1, 3, 6, 7, 9], &smooth).unwrap_err().to_string(),
+            "1 3 6 breaks smooth"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use common::{
+    geom::{Point, Vector},
+    parallel,
+    random::{self, Rng},
+    search::bfs,
+    solution::Settings,
+    Answer, AocError, Grid, Solution,
+};
+
+const MIN_TIME_SAVED: usize = 100;
+
+pub struct Day20;
+
+/// The racetrack, and how many picoseconds a cheat must save to count. The
+/// statement's examples count smaller savings, set by their `saved` setting.
+pub struct Racetrack {
+    map: Grid<char>,
+    min_saved: usize,
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Racetrack;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let map = Grid::parse(input)?;
+        for (tile, name) in [('S', "start"), ('E', "end")] {
+            if map.find(&tile).is_none() {
+                return Err(AocError::new(format!(
+                    "no {} tile `{}` on the racetrack",
+                    name, tile
+                )));
+            }
+        }
+        Ok(Racetrack {
+            map,
+            min_saved: MIN_TIME_SAVED,
+        })
+    }
+
+    fn configure(track: &mut Self::Input, settings: &Settings) -> Result<(), AocError> {
+        for (name, &value) in settings {
+            match name.as_str() {
+                "saved" => track.min_saved = value,
+                _ => return Err(AocError::new(format!("unknown setting `{name}`"))),
+            }
+        }
+        Ok(())
+    }
+
+    fn part1(track: &Self::Input) -> Answer {
+        cheats_answer(count_cheats(track, 2))
+    }
+
+    fn part2(track: &Self::Input) -> Answer {
+        cheats_answer(count_cheats(track, 20))
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let maze = random::maze(rng, size.unwrap_or(141), 0.0);
+        Some(random::track(&maze).to_string())
+    }
+}
+
+fn cheats_answer(count: Option<usize>) -> Answer {
+    match count {
+        Some(count) => count.into(),
+        None => "No path to end even without cheating.".into(),
+    }
+}
+
+/// Counts the cheats of at most `max_cheat_steps` that save at least the
+/// track's `min_saved` picoseconds, or `None` if the end is unreachable.
+fn count_cheats(racetrack: &Racetrack, max_cheat_steps: usize) -> Option<usize> {
+    let Racetrack { map, min_saved } = racetrack;
+    let start = map.find(&'S')?;
+    let end = map.find(&'E')?;
+
+    let is_track = |p: Point| {
+        map.get(p)
+            .is_some_and(|&ch| ch == '.' || ch == 'S' || ch == 'E')
+    };
+    let along_track = |&p: &Point| map.neighbors4(p).filter(move |&next| is_track(next));
+
+    let dist_no_cheat_from_s = bfs([start], along_track);
+    let dist_no_cheat_to_e = bfs([end], along_track);
+    let t_no_cheat = dist_no_cheat_from_s.cost(&end)?;
+
+    let reach = max_cheat_steps as isize;
+    let track: Vec<(Point, usize)> = dist_no_cheat_from_s
+        .costs()
+        .iter()
+        .map(|(&from, &start_dist)| (from, start_dist))
+        .collect();
+
+    let saving_cheats = parallel::map(&track, |&(from, start_dist)| {
+        // During cheat steps walls don't matter, so every cell within
+        // `max_cheat_steps` is reached in its Manhattan distance
+        let mut count = 0;
+        for dr in -reach..=reach {
+            let span = reach - dr.abs();
+            for dc in -span..=span {
+                let Some(to) = map.step(from, Vector::new(dr, dc)) else {
+                    continue;
+                };
+                let Some(after_dist) = dist_no_cheat_to_e.cost(&to) else {
+                    continue;
+                };
+
+                let steps_used = from.manhattan(to);
+                let t_with_cheat = start_dist + steps_used + after_dist;
+                if steps_used > 0 && t_with_cheat + min_saved <= t_no_cheat {
+                    count += 1;
+                }
+            }
+        }
+        count
+    });
+    Some(saving_cheats.into_iter().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day20);
+}
@@ -0,0 +1,162 @@
+use common::{
+    geom::{Direction8, Point},
+    Answer, AocError, Grid, Solution,
+};
+
+fn count_overlapping_substrings(s: &str, sub: &str) -> usize {
+    if sub.is_empty() {
+        return 0;
+    }
+    let mut count = 0;
+    let mut pos = 0;
+    while pos + sub.len() <= s.len() {
+        if &s[pos..pos + sub.len()] == sub {
+            count += 1;
+        }
+        pos += 1; // Move one character forward to allow overlapping matches
+    }
+    count
+}
+
+fn parse_horizontal(grid: &Grid<char>, word: &str) -> usize {
+    grid.rows()
+        .map(|row| {
+            let line: String = row.iter().collect();
+            count_overlapping_substrings(&line, word)
+                + count_overlapping_substrings(&line.chars().rev().collect::<String>(), word)
+        })
+        .sum()
+}
+
+fn parse_vertical(grid: &Grid<char>, word: &str) -> usize {
+    let num_rows = grid.height();
+    let num_cols = grid.width();
+    let mut total = 0;
+
+    for col in 0..num_cols {
+        let column_string: String = (0..num_rows)
+            .map(|row| grid[Point::new(row, col)])
+            .collect();
+
+        total += count_overlapping_substrings(&column_string, word);
+        let reversed_column: String = column_string.chars().rev().collect();
+        total += count_overlapping_substrings(&reversed_column, word);
+    }
+
+    total
+}
+
+fn parse_diagonals(grid: &Grid<char>, word: &str) -> usize {
+    let num_rows = grid.height();
+    let num_cols = grid.width();
+    let mut total = 0;
+
+    // Diagonals from top-left to bottom-right (down-right)
+    for k in 0..(num_rows + num_cols - 1) {
+        let mut diagonal = String::new();
+
+        let row_start = k.saturating_sub(num_cols - 1);
+        let row_end = k.min(num_rows - 1);
+
+        for i in row_start..=row_end {
+            let row = i;
+            let col = k - i;
+            diagonal.push(grid[Point::new(row, col)]);
+        }
+
+        if !diagonal.is_empty() {
+            total += count_overlapping_substrings(&diagonal, word);
+            let reversed_diagonal: String = diagonal.chars().rev().collect();
+            total += count_overlapping_substrings(&reversed_diagonal, word);
+        }
+    }
+
+    // Diagonals from top-right to bottom-left (down-left)
+    for k in 0..(num_rows + num_cols - 1) {
+        let mut diagonal = String::new();
+
+        let row_start = k.saturating_sub(num_cols - 1);
+        let row_end = k.min(num_rows - 1);
+
+        for i in row_start..=row_end {
+            let row = i;
+            let col = (num_cols - 1) - (k - i);
+            diagonal.push(grid[Point::new(row, col)]);
+        }
+
+        if !diagonal.is_empty() {
+            total += count_overlapping_substrings(&diagonal, word);
+            let reversed_diagonal: String = diagonal.chars().rev().collect();
+            total += count_overlapping_substrings(&reversed_diagonal, word);
+        }
+    }
+
+    total
+}
+
+fn count_x_mas(grid: &Grid<char>) -> usize {
+    let mut total = 0;
+
+    // Neighbouring cell, or a blank if that falls off the grid
+    let at = |pos, dir: Direction8| grid.step(pos, dir).map_or(' ', |p| grid[p]);
+
+    for (pos, &cell) in grid.iter() {
+        if cell == 'A' {
+            // Check primary diagonal (top-left to bottom-right)
+            let mut primary_matches = 0;
+            let top_left = at(pos, Direction8::UpLeft);
+            let bottom_right = at(pos, Direction8::DownRight);
+
+            if (top_left == 'M' && bottom_right == 'S') || (top_left == 'S' && bottom_right == 'M')
+            {
+                primary_matches = 1;
+            }
+
+            // Check secondary diagonal (top-right to bottom-left)
+            let mut secondary_matches = 0;
+            let top_right = at(pos, Direction8::UpRight);
+            let bottom_left = at(pos, Direction8::DownLeft);
+
+            if (top_right == 'M' && bottom_left == 'S') || (top_right == 'S' && bottom_left == 'M')
+            {
+                secondary_matches = 1;
+            }
+
+            // If both diagonals form valid "MAS" or "SAM", increment total
+            total += primary_matches * secondary_matches;
+        }
+    }
+
+    total
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let word = "XMAS";
+
+        let horizontal = parse_horizontal(grid, word);
+        let vertical = parse_vertical(grid, word);
+        let diagonal = parse_diagonals(grid, word);
+
+        (horizontal + vertical + diagonal).into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        count_x_mas(grid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day4);
+}
@@ -0,0 +1,232 @@
+use std::collections::HashSet;
+
+use common::{
+    animate::Recorder,
+    differential::Differential,
+    geom::{Direction4, Point},
+    random::Rng,
+    render::{map_cell, Canvas, Cell, Rgb},
+    Answer, AocError, Grid, Solution,
+};
+
+pub mod test;
+
+const OBSTACLE: char = '#';
+
+pub struct Lab {
+    grid: Grid<char>,
+    guard: (Point, Direction4),
+}
+
+fn guard_direction(cell: char) -> Option<Direction4> {
+    match cell {
+        '^' => Some(Direction4::Up),
+        '>' => Some(Direction4::Right),
+        'v' => Some(Direction4::Down),
+        '<' => Some(Direction4::Left),
+        _ => None,
+    }
+}
+
+/// Finds the guard's starting position and direction.
+fn find_guard(grid: &Grid<char>) -> Option<(Point, Direction4)> {
+    let pos = grid.position(|&cell| guard_direction(cell).is_some())?;
+    Some((pos, guard_direction(grid[pos])?))
+}
+
+fn guard_glyph(dir: Direction4) -> char {
+    match dir {
+        Direction4::Up => '^',
+        Direction4::Right => '>',
+        Direction4::Down => 'v',
+        Direction4::Left => '<',
+    }
+}
+
+/// Walks the guard until they leave the map, calling `visit` with where
+/// they start and with their state after every step or turn.
+fn walk(lab: &Lab, mut visit: impl FnMut(Point, Direction4)) {
+    let grid = &lab.grid;
+    let (mut pos, mut dir) = lab.guard;
+    visit(pos, dir);
+
+    // To prevent infinite loops, track visited states (position + direction)
+    let mut seen_states = HashSet::new();
+    seen_states.insert((pos, dir));
+
+    // If next step is out-of-bounds, guard leaves the area
+    while let Some(next) = grid.step(pos, dir) {
+        // If there's an obstacle, turn right
+        if grid[next] == OBSTACLE {
+            dir = dir.turn_right();
+        } else {
+            // Move forward
+            pos = next;
+        }
+        visit(pos, dir);
+
+        // Check for repeated state to prevent infinite loops
+        if !seen_states.insert((pos, dir)) {
+            common::debug!("Detected a repeating state: infinite loop prevented.");
+            break;
+        }
+    }
+}
+
+fn count_visited(lab: &Lab) -> usize {
+    let mut visited_positions = HashSet::new();
+    walk(lab, |pos, _| {
+        visited_positions.insert(pos);
+    });
+    visited_positions.len()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(input)?;
+        let guard = find_guard(&grid)
+            .ok_or_else(|| AocError::new("no guard (`^`, `>`, `v` or `<`) found in the map"))?;
+        Ok(Lab { grid, guard })
+    }
+
+    fn part1(lab: &Self::Input) -> Answer {
+        count_visited(lab).into()
+    }
+
+    fn part2(lab: &Self::Input) -> Answer {
+        test::test::day6_p2(&lab.grid).into()
+    }
+
+    fn render(lab: &Self::Input) -> Option<Canvas> {
+        let mut route = vec![];
+        walk(lab, |pos, _| {
+            if route.last() != Some(&pos) {
+                route.push(pos);
+            }
+        });
+
+        let mut canvas = Canvas::from_grid(&lab.grid, map_cell);
+        canvas.path(&route, Rgb::YELLOW);
+        canvas.highlight([lab.guard.0], Rgb::GREEN);
+        Some(canvas)
+    }
+
+    fn animate(lab: &Self::Input, recorder: &mut Recorder) {
+        let mut canvas = Canvas::from_grid(&lab.grid, map_cell);
+        let mut last = lab.guard.0;
+        walk(lab, |pos, dir| {
+            // Leave an `X` behind, as in the puzzle statement
+            if let Some(cell) = canvas.cell_mut(last) {
+                *cell = Cell::new('X', Rgb::BLACK, Rgb::YELLOW);
+            }
+            if let Some(cell) = canvas.cell_mut(pos) {
+                *cell = Cell::new(guard_glyph(dir), Rgb::BLACK, Rgb::GREEN);
+            }
+            last = pos;
+            recorder.frame(|| canvas.clone());
+        });
+        recorder.finish(|| canvas.clone());
+    }
+}
+
+/// Whether the guard walks off the map rather than round in a loop.
+fn escapes(grid: &Grid<char>) -> bool {
+    let Some((mut pos, mut dir)) = find_guard(grid) else {
+        return false;
+    };
+    let mut seen_states = HashSet::new();
+    while seen_states.insert((pos, dir)) {
+        match grid.step(pos, dir) {
+            None => return true,
+            Some(next) if grid[next] == OBSTACLE => dir = dir.turn_right(),
+            Some(next) => pos = next,
+        }
+    }
+    false
+}
+
+/// `count_visited` against the older walk in `test`, on labs the guard
+/// leaves (the older walk has no loop check).
+pub struct GuardWalk;
+
+impl Differential for GuardWalk {
+    const DAY: u8 = 6;
+    const NAME: &'static str = "guard walk";
+    const MAX_SIZE: usize = 12;
+
+    type Input = Grid<char>;
+    type Output = usize;
+
+    fn reference(grid: &Grid<char>) -> usize {
+        let guard = find_guard(grid).expect("generated labs have a guard");
+        count_visited(&Lab {
+            grid: grid.clone(),
+            guard,
+        })
+    }
+
+    fn fast(grid: &Grid<char>) -> usize {
+        test::test::day6_p1(grid)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Grid<char> {
+        loop {
+            let mut grid = Grid::new(size, size, '.');
+            for pos in grid.positions().collect::<Vec<_>>() {
+                if rng.chance(0.15) {
+                    grid[pos] = OBSTACLE;
+                }
+            }
+            let guard = Point::new(rng.below(size), rng.below(size));
+            grid[guard] = *rng.pick(&['^', '>', 'v', '<']);
+            if escapes(&grid) {
+                return grid;
+            }
+        }
+    }
+
+    fn shrink(grid: &Grid<char>) -> Vec<Grid<char>> {
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let (width, height) = (grid.width(), grid.height());
+        let mut smaller = vec![];
+
+        // Drop an outer row or column, then clear single obstacles
+        let mut trimmed = vec![];
+        if height > 1 {
+            trimmed.push(rows[1..].to_vec());
+            trimmed.push(rows[..height - 1].to_vec());
+        }
+        if width > 1 {
+            trimmed.push(rows.iter().map(|row| row[1..].to_string()).collect());
+            trimmed.push(
+                rows.iter()
+                    .map(|row| row[..width - 1].to_string())
+                    .collect(),
+            );
+        }
+        for rows in trimmed {
+            if let Ok(grid) = Grid::parse(&rows.join("\n")) {
+                smaller.push(grid);
+            }
+        }
+        for pos in grid.positions().filter(|&pos| grid[pos] == OBSTACLE) {
+            let mut cleared = grid.clone();
+            cleared[pos] = '.';
+            smaller.push(cleared);
+        }
+
+        smaller.retain(escapes);
+        smaller
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(super::Day6);
+}
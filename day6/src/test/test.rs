@@ -1,103 +1,48 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
+use std::collections::HashSet;
+
+use common::{
+    geom::{Direction4, Point},
+    parallel, Grid,
 };
 
 /* Advent of Code - Day 6 - Surasia */
 
-const PATH_TO_INPUT: &str = "input.txt";
-const LINE_WIDTH: usize = 130;
-const LINE_COUNT: usize = 130;
-
-#[derive(PartialEq, Eq, Debug, Clone, Hash, Copy)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Bottom,
-}
-
-#[derive(PartialEq, Eq, Debug)]
-struct GuardPosition(usize, usize, Direction);
-
-fn figure_out_guard(pos: &mut GuardPosition, grid: &[Vec<char>]) -> Option<(usize, usize)> {
-    let last_pos = &pos.2;
-    let mut position = (0, 0);
-    match last_pos {
-        Direction::Up => {
-            if pos.1 == 0 {
-                return None;
-            }
-            if grid[pos.0][pos.1 - 1] == '#' {
-                pos.2 = Direction::Right;
-            } else {
-                pos.1 -= 1;
-                position = (pos.0, pos.1);
-            }
-        }
-        Direction::Bottom => {
-            if pos.1 == LINE_COUNT - 1 {
-                return None;
-            }
-            if grid[pos.0][pos.1 + 1] == '#' {
-                pos.2 = Direction::Left;
-            } else {
-                pos.1 += 1;
-                position = (pos.0, pos.1);
-            };
-        }
-        Direction::Left => {
-            if pos.0 == 0 {
-                return None;
-            }
-            if grid[pos.0 - 1][pos.1] == '#' {
-                pos.2 = Direction::Up;
-            } else {
-                pos.0 -= 1;
-                position = (pos.0, pos.1);
-            }
-        }
-        Direction::Right => {
-            if pos.0 == LINE_COUNT - 1 {
-                return None;
-            }
-            if grid[pos.0 + 1][pos.1] == '#' {
-                pos.2 = Direction::Bottom;
-            } else {
-                pos.0 += 1;
-                position = (pos.0, pos.1);
-            }
-        }
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+struct GuardPosition(Point, Direction4);
+
+fn figure_out_guard(pos: &mut GuardPosition, grid: &Grid<char>) -> Option<Point> {
+    let next = grid.step(pos.0, pos.1)?;
+    let mut position = Point::default();
+    if grid[next] == '#' {
+        pos.1 = pos.1.turn_right();
+    } else {
+        pos.0 = next;
+        position = next;
     }
     Some(position)
 }
 
-fn create_grid(reader: BufReader<File>) -> (Vec<Vec<char>>, GuardPosition) {
-    let mut grid = vec![vec![' '; LINE_WIDTH]; LINE_COUNT];
-    let mut guard_position = GuardPosition(0, 0, Direction::Up);
-
-    for (idx, line) in reader.lines().map_while(Result::ok).enumerate() {
-        for c in line.char_indices() {
-            match c.1 {
-                '^' => guard_position = GuardPosition(c.0, idx, Direction::Up),
-                '>' => guard_position = GuardPosition(c.0, idx, Direction::Right),
-                '<' => guard_position = GuardPosition(c.0, idx, Direction::Left),
-                'v' => guard_position = GuardPosition(c.0, idx, Direction::Bottom),
-                _ => {}
-            }
-            grid[c.0][idx] = c.1;
+fn create_grid(grid: &Grid<char>) -> (Grid<char>, GuardPosition) {
+    let mut guard_position = GuardPosition(Point::default(), Direction4::Up);
+
+    for (pos, &c) in grid.iter() {
+        match c {
+            '^' => guard_position = GuardPosition(pos, Direction4::Up),
+            '>' => guard_position = GuardPosition(pos, Direction4::Right),
+            '<' => guard_position = GuardPosition(pos, Direction4::Left),
+            'v' => guard_position = GuardPosition(pos, Direction4::Down),
+            _ => {}
         }
     }
-    (grid, guard_position)
+    (grid.clone(), guard_position)
 }
 
-fn calculate_path(grid: &[Vec<char>], guard_position: GuardPosition) -> HashSet<(usize, usize)> {
+fn calculate_path(grid: &Grid<char>, guard_position: GuardPosition) -> HashSet<Point> {
     let mut all_positions = HashSet::new();
     let mut curr_pos = guard_position;
 
-    all_positions.insert((curr_pos.0, curr_pos.1));
-    while curr_pos.0 < LINE_COUNT && curr_pos.1 < LINE_COUNT {
+    all_positions.insert(curr_pos.0);
+    while grid.contains(curr_pos.0) {
         if let Some(pos) = figure_out_guard(&mut curr_pos, grid) {
             all_positions.insert(pos);
         } else {
@@ -107,49 +52,41 @@ fn calculate_path(grid: &[Vec<char>], guard_position: GuardPosition) -> HashSet<
     all_positions
 }
 
-pub fn day6_p1() {
-    let file = File::open(PATH_TO_INPUT).expect("Failed to open file!");
-    let br = BufReader::new(file);
-    let (grid, guard_position) = create_grid(br);
+pub fn day6_p1(rows: &Grid<char>) -> usize {
+    let (grid, guard_position) = create_grid(rows);
 
     let path = calculate_path(&grid, guard_position);
-    println!("Day 6 possible steps: {}", path.len() - 1);
+    path.len() - 1
 }
 
-pub fn day6_p2() {
-    let file = File::open(PATH_TO_INPUT).expect("Failed to open file!");
-    let br = BufReader::new(file);
-    let (grid, guard_position) = create_grid(br);
+pub fn day6_p2(rows: &Grid<char>) -> usize {
+    let (grid, guard_position) = create_grid(rows);
 
-    let start_pos = (guard_position.0, guard_position.1);
-    let mut valid_positions = 0;
+    let start_pos = guard_position.0;
+    let candidates: Vec<Point> = grid
+        .positions()
+        .filter(|&pos| pos != start_pos && grid[pos] != '#')
+        .collect();
 
-    for y in 0..LINE_COUNT {
-        for x in 0..LINE_WIDTH {
-            if (x, y) == start_pos || grid[x][y] == '#' {
-                continue;
-            }
+    // Each obstruction is tried on its own copy of the grid
+    let loops = parallel::map(&candidates, |&pos| {
+        let mut test_grid = grid.clone();
+        test_grid[pos] = '#';
+
+        let mut curr_pos = guard_position;
+        let mut visited = HashSet::new();
+        visited.insert(curr_pos);
 
-            let mut test_grid = grid.clone();
-            test_grid[x][y] = '#';
-
-            let mut curr_pos = GuardPosition(guard_position.0, guard_position.1, guard_position.2);
-            let mut visited = HashSet::new();
-            visited.insert((curr_pos.0, curr_pos.1, curr_pos.2));
-
-            loop {
-                if figure_out_guard(&mut curr_pos, &test_grid).is_none() {
-                    break;
-                }
-                let state = (curr_pos.0, curr_pos.1, curr_pos.2);
-                if visited.contains(&state) {
-                    valid_positions += 1;
-                    break;
-                }
-                visited.insert(state);
+        loop {
+            if figure_out_guard(&mut curr_pos, &test_grid).is_none() {
+                return false;
             }
+            if visited.contains(&curr_pos) {
+                return true;
+            }
+            visited.insert(curr_pos);
         }
-    }
+    });
 
-    println!("Day 6 possible obstructions: {valid_positions}");
+    loops.into_iter().filter(|&loops| loops).count()
 }
@@ -1,22 +1,24 @@
-use std::fs;
+use common::{random::Rng, Answer, AocError, Solution};
 
 #[derive(Debug)]
 struct DiskBlock {
     file_id: Option<usize>,
-    position: usize,
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap())
+fn parse_input(input: &str) -> Result<Vec<u32>, AocError> {
+    let line = input.trim();
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).ok_or_else(|| {
+                let token = &line[i..i + c.len_utf8()];
+                AocError::invalid_token(0, line, token, "expected a digit")
+            })
+        })
         .collect()
 }
 
 fn create_disk_map(lengths: &[u32]) -> Vec<DiskBlock> {
     let mut disk = Vec::new();
-    let mut current_pos = 0;
     let mut file_id = 0;
 
     for (i, &length) in lengths.iter().enumerate() {
@@ -25,26 +27,20 @@ fn create_disk_map(lengths: &[u32]) -> Vec<DiskBlock> {
             for _ in 0..length {
                 disk.push(DiskBlock {
                     file_id: Some(file_id),
-                    position: current_pos,
                 });
-                current_pos += 1;
             }
             file_id += 1;
         } else {
             // Free space
             for _ in 0..length {
-                disk.push(DiskBlock {
-                    file_id: None,
-                    position: current_pos,
-                });
-                current_pos += 1;
+                disk.push(DiskBlock { file_id: None });
             }
         }
     }
     disk
 }
 
-fn defragment(disk: &mut Vec<DiskBlock>) {
+fn defragment(disk: &mut [DiskBlock]) {
     let len = disk.len();
     for i in 0..len {
         if disk[i].file_id.is_none() {
@@ -97,7 +93,7 @@ fn find_free_space(disk: &[DiskBlock], size_needed: usize) -> Option<usize> {
     None
 }
 
-fn defragment_whole_files(disk: &mut Vec<DiskBlock>) {
+fn defragment_whole_files(disk: &mut [DiskBlock]) {
     // Get highest file ID
     let max_file_id = disk
         .iter()
@@ -128,12 +124,75 @@ fn defragment_whole_files(disk: &mut Vec<DiskBlock>) {
     }
 }
 
-// Modify main() to use new defragmentation:
-fn main() {
-    let input = fs::read_to_string("input.txt").expect("Failed to read input.txt");
-    let lengths = parse_input(&input);
-    let mut disk = create_disk_map(&lengths);
-    defragment_whole_files(&mut disk); // Changed this line
-    let checksum = calculate_checksum(&disk);
-    println!("Filesystem checksum: {}", checksum);
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(lengths: &Self::Input) -> Answer {
+        let mut disk = create_disk_map(lengths);
+        defragment(&mut disk);
+        calculate_checksum(&disk).into()
+    }
+
+    fn part2(lengths: &Self::Input) -> Answer {
+        let mut disk = create_disk_map(lengths);
+        defragment_whole_files(&mut disk);
+        calculate_checksum(&disk).into()
+    }
+
+    fn generate(rng: &mut Rng, size: Option<usize>) -> Option<String> {
+        let files = size.unwrap_or(10_000).max(1);
+        let mut input = String::new();
+        for i in 0..files {
+            if i > 0 {
+                input.push_str(&rng.range(0..10).to_string());
+            }
+            input.push_str(&rng.range(1..10).to_string());
+        }
+        input.push('\n');
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{property, random::Rng, Solution};
+
+    use super::*;
+
+    common::example_tests!(super::Day9);
+
+    fn disk_map(rng: &mut Rng, size: usize) -> Vec<u32> {
+        Day9::parse(&Day9::generate(rng, Some(size)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn moving_whole_files_keeps_them_whole_and_only_moves_left() {
+        property::check(40, disk_map, |lengths| {
+            let before = create_disk_map(lengths);
+            let mut after = create_disk_map(lengths);
+            defragment_whole_files(&mut after);
+
+            assert_eq!(before.len(), after.len());
+            for file_id in 0..lengths.len().div_ceil(2) {
+                let (old, new) = (
+                    get_file_blocks(&before, file_id),
+                    get_file_blocks(&after, file_id),
+                );
+                assert_eq!(old.len(), new.len(), "file {file_id} changed size");
+                assert!(
+                    new.windows(2).all(|pair| pair[1] == pair[0] + 1),
+                    "file {file_id} was split"
+                );
+                assert!(new.first() <= old.first(), "file {file_id} moved right");
+            }
+        });
+    }
 }